@@ -6,7 +6,11 @@ use criterion::{
     black_box, criterion_group, criterion_main, AxisScale, BenchmarkId, Criterion,
     PlotConfiguration,
 };
-use ibig::{modular::ModuloRing, ops::DivRem, ubig, UBig};
+use ibig::{
+    modular::{ModuloRing, MontgomeryCtx},
+    ops::DivRem,
+    ubig, UBig,
+};
 use rand::prelude::*;
 use std::fmt::Write;
 
@@ -216,8 +220,8 @@ fn bench_modulo_mul(criterion: &mut Criterion) {
         let bits = 10usize.pow(log_bits);
         let m = random_ubig(bits, &mut rng);
         let ring = ModuloRing::new(&m);
-        let a = ring.from(&random_ubig(bits, &mut rng));
-        let b = ring.from(&random_ubig(bits, &mut rng));
+        let a = ring.from(random_ubig(bits, &mut rng));
+        let b = ring.from(random_ubig(bits, &mut rng));
         group.bench_with_input(BenchmarkId::from_parameter(bits), &bits, |bencher, _| {
             bencher.iter(|| black_box(&a) * black_box(&b))
         });
@@ -238,7 +242,7 @@ fn bench_modulo_pow(criterion: &mut Criterion) {
         let bits = 10usize.pow(log_bits);
         let m = random_ubig(bits, &mut rng);
         let ring = ModuloRing::new(&m);
-        let a = ring.from(&random_ubig(bits, &mut rng));
+        let a = ring.from(random_ubig(bits, &mut rng));
         let b = random_ubig(bits, &mut rng);
         group.bench_with_input(BenchmarkId::from_parameter(bits), &bits, |bencher, _| {
             bencher.iter(|| black_box(&a).pow(&b))
@@ -247,6 +251,68 @@ fn bench_modulo_pow(criterion: &mut Criterion) {
 
     group.finish();
 }
+
+fn bench_montgomery_pow(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("montgomery_pow");
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    for log_bits in 1..=4 {
+        if log_bits == 4 {
+            group.sample_size(10);
+        }
+        let bits = 10usize.pow(log_bits);
+        let m = random_ubig(bits, &mut rng) | ubig!(1);
+        let ctx = MontgomeryCtx::new(&m).unwrap();
+        let a = random_ubig(bits, &mut rng);
+        let b = random_ubig(bits, &mut rng);
+        group.bench_with_input(BenchmarkId::from_parameter(bits), &bits, |bencher, _| {
+            bencher.iter(|| ctx.pow_mod(black_box(&a), black_box(&b)))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_product(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("product");
+
+    let factors: Vec<UBig> = (0..10000).map(|_| random_ubig(64, &mut rng)).collect();
+    group.bench_function("fold", |bencher| {
+        bencher.iter(|| {
+            black_box(&factors)
+                .iter()
+                .fold(ubig!(1), |acc, factor| acc * factor)
+        })
+    });
+    group.bench_function("tree", |bencher| {
+        bencher.iter(|| UBig::product_of_slice(black_box(&factors)))
+    });
+
+    group.finish();
+}
+
+#[cfg(feature = "rayon")]
+fn bench_par_product(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("par_product");
+    group.sample_size(10);
+
+    let factors: Vec<UBig> = (0..50000).map(|_| random_ubig(64, &mut rng)).collect();
+    group.bench_function("serial", |bencher| {
+        bencher.iter(|| UBig::product_of_slice(black_box(&factors)))
+    });
+    group.bench_function("parallel", |bencher| {
+        bencher.iter(|| UBig::par_product_of_slice(black_box(&factors)))
+    });
+
+    group.finish();
+}
+
+#[cfg(not(feature = "rayon"))]
+fn bench_par_product(_criterion: &mut Criterion) {}
+
 criterion_group!(
     benches,
     bench_add,
@@ -261,6 +327,9 @@ criterion_group!(
     bench_pow,
     bench_modulo_mul,
     bench_modulo_pow,
+    bench_montgomery_pow,
+    bench_product,
+    bench_par_product,
 );
 
 criterion_main!(benches);
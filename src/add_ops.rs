@@ -11,6 +11,7 @@ use crate::{
     ubig::{Repr::*, UBig},
 };
 use core::{
+    iter::Sum,
     mem,
     ops::{Add, AddAssign, Sub, SubAssign},
 };
@@ -21,7 +22,7 @@ impl Add<UBig> for UBig {
     #[inline]
     fn add(self, rhs: UBig) -> UBig {
         match (self.into_repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::add_word(word0, word1),
+            (Small(word0), Small(word1)) => UBig::add_word_word(word0, word1),
             (Small(word0), Large(buffer1)) => UBig::add_large_word(buffer1, word0),
             (Large(buffer0), Small(word1)) => UBig::add_large_word(buffer0, word1),
             (Large(buffer0), Large(buffer1)) => {
@@ -41,7 +42,7 @@ impl Add<&UBig> for UBig {
     #[inline]
     fn add(self, rhs: &UBig) -> UBig {
         match (self.into_repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::add_word(word0, *word1),
+            (Small(word0), Small(word1)) => UBig::add_word_word(word0, *word1),
             (Small(word0), Large(buffer1)) => UBig::add_large_word(buffer1.clone(), word0),
             (Large(buffer0), Small(word1)) => UBig::add_large_word(buffer0, *word1),
             (Large(buffer0), Large(buffer1)) => UBig::add_large(buffer0, buffer1),
@@ -64,7 +65,7 @@ impl Add<&UBig> for &UBig {
     #[inline]
     fn add(self, rhs: &UBig) -> UBig {
         match (self.repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::add_word(*word0, *word1),
+            (Small(word0), Small(word1)) => UBig::add_word_word(*word0, *word1),
             (Small(word0), Large(buffer1)) => UBig::add_large_word(buffer1.clone(), *word0),
             (Large(buffer0), Small(word1)) => UBig::add_large_word(buffer0.clone(), *word1),
             (Large(buffer0), Large(buffer1)) => {
@@ -98,7 +99,7 @@ impl Sub<UBig> for UBig {
     #[inline]
     fn sub(self, rhs: UBig) -> UBig {
         match (self.into_repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::sub_word(word0, word1),
+            (Small(word0), Small(word1)) => UBig::sub_word_word(word0, word1),
             (Small(_), Large(_)) => UBig::panic_negative(),
             (Large(buffer0), Small(word1)) => UBig::sub_large_word(buffer0, word1),
             (Large(buffer0), Large(buffer1)) => UBig::sub_large(buffer0, &buffer1),
@@ -112,7 +113,7 @@ impl Sub<&UBig> for UBig {
     #[inline]
     fn sub(self, rhs: &UBig) -> UBig {
         match (self.into_repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::sub_word(word0, *word1),
+            (Small(word0), Small(word1)) => UBig::sub_word_word(word0, *word1),
             (Small(_), Large(_)) => UBig::panic_negative(),
             (Large(buffer0), Small(word1)) => UBig::sub_large_word(buffer0, *word1),
             (Large(buffer0), Large(buffer1)) => UBig::sub_large(buffer0, buffer1),
@@ -126,7 +127,7 @@ impl Sub<UBig> for &UBig {
     #[inline]
     fn sub(self, rhs: UBig) -> UBig {
         match (self.repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::sub_word(*word0, word1),
+            (Small(word0), Small(word1)) => UBig::sub_word_word(*word0, word1),
             (Small(_), Large(_)) => UBig::panic_negative(),
             (Large(buffer0), Small(word1)) => UBig::sub_large_word(buffer0.clone(), word1),
             (Large(buffer0), Large(buffer1)) => UBig::sub_large_ref_val(buffer0, buffer1),
@@ -140,7 +141,7 @@ impl Sub<&UBig> for &UBig {
     #[inline]
     fn sub(self, rhs: &UBig) -> UBig {
         match (self.repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::sub_word(*word0, *word1),
+            (Small(word0), Small(word1)) => UBig::sub_word_word(*word0, *word1),
             (Small(_), Large(_)) => UBig::panic_negative(),
             (Large(buffer0), Small(word1)) => UBig::sub_large_word(buffer0.clone(), *word1),
             (Large(buffer0), Large(buffer1)) => UBig::sub_large(buffer0.clone(), buffer1),
@@ -290,6 +291,46 @@ impl SubAssign<&IBig> for IBig {
     }
 }
 
+impl Sum<UBig> for UBig {
+    fn sum<I: Iterator<Item = UBig>>(iter: I) -> UBig {
+        let mut res = UBig::zero();
+        for x in iter {
+            res += x;
+        }
+        res
+    }
+}
+
+impl<'a> Sum<&'a UBig> for UBig {
+    fn sum<I: Iterator<Item = &'a UBig>>(iter: I) -> UBig {
+        let mut res = UBig::zero();
+        for x in iter {
+            res += x;
+        }
+        res
+    }
+}
+
+impl Sum<IBig> for IBig {
+    fn sum<I: Iterator<Item = IBig>>(iter: I) -> IBig {
+        let mut res = IBig::zero();
+        for x in iter {
+            res += x;
+        }
+        res
+    }
+}
+
+impl<'a> Sum<&'a IBig> for IBig {
+    fn sum<I: Iterator<Item = &'a IBig>>(iter: I) -> IBig {
+        let mut res = IBig::zero();
+        for x in iter {
+            res += x;
+        }
+        res
+    }
+}
+
 macro_rules! impl_add_ubig_unsigned {
     ($t:ty) => {
         impl Add<$t> for UBig {
@@ -526,9 +567,223 @@ impl_add_ibig_primitive!(i128);
 impl_add_ibig_primitive!(isize);
 
 impl UBig {
+    /// Checked subtraction.
+    ///
+    /// Returns `None` if the result would be negative. The `-` operator panics in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).checked_sub(&ubig!(3)), Some(ubig!(2)));
+    /// assert_eq!(ubig!(3).checked_sub(&ubig!(5)), None);
+    /// ```
+    #[inline]
+    pub fn checked_sub(&self, rhs: &UBig) -> Option<UBig> {
+        match (self.repr(), rhs.repr()) {
+            (Small(word0), Small(word1)) => word0.checked_sub(*word1).map(UBig::from_word),
+            (Small(_), Large(_)) => None,
+            (Large(buffer0), Small(word1)) => Some(UBig::sub_large_word(buffer0.clone(), *word1)),
+            (Large(buffer0), Large(buffer1)) => UBig::checked_sub_large(buffer0.clone(), buffer1),
+        }
+    }
+
+    /// Saturating subtraction.
+    ///
+    /// Returns 0 if `rhs` is greater than `self`. The `-` operator panics in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).saturating_sub(&ubig!(3)), ubig!(2));
+    /// assert_eq!(ubig!(3).saturating_sub(&ubig!(5)), ubig!(0));
+    /// ```
+    #[inline]
+    pub fn saturating_sub(&self, rhs: &UBig) -> UBig {
+        if self > rhs {
+            self - rhs
+        } else {
+            UBig::from_word(0)
+        }
+    }
+
+    /// Absolute difference `|self - rhs|`.
+    ///
+    /// Unlike the `-` operator, this never panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).abs_diff(&ubig!(3)), ubig!(2));
+    /// assert_eq!(ubig!(3).abs_diff(&ubig!(5)), ubig!(2));
+    /// ```
+    #[inline]
+    pub fn abs_diff(&self, rhs: &UBig) -> UBig {
+        if self >= rhs {
+            self - rhs
+        } else {
+            rhs - self
+        }
+    }
+
+    /// Subtract `modulus` once if `self >= modulus`.
+    ///
+    /// If `self < 2 * modulus`, for example the sum of two numbers less than `modulus`, the
+    /// result is `self mod modulus` without any division.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(15);
+    /// a.reduce_once(&ubig!(10));
+    /// assert_eq!(a, ubig!(5));
+    /// a.reduce_once(&ubig!(10));
+    /// assert_eq!(a, ubig!(5));
+    /// ```
+    #[inline]
+    pub fn reduce_once(&mut self, modulus: &UBig) {
+        if *self >= *modulus {
+            *self -= modulus;
+        }
+    }
+
+    /// Subtract `modulus` up to twice, as long as `self >= modulus`.
+    ///
+    /// If `self < 3 * modulus`, for example the sum of a number less than `modulus` and one
+    /// less than `2 * modulus`, the result is `self mod modulus` without any division.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(25);
+    /// a.reduce_twice(&ubig!(10));
+    /// assert_eq!(a, ubig!(5));
+    /// ```
+    #[inline]
+    pub fn reduce_twice(&mut self, modulus: &UBig) {
+        self.reduce_once(modulus);
+        self.reduce_once(modulus);
+    }
+
+    /// Checked addition.
+    ///
+    /// Returns `None` if the result would have more than [UBig::MAX_BIT_LEN] bits.
+    /// The `+` operator panics in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).checked_add(&ubig!(3)), Some(ubig!(8)));
+    /// ```
+    #[inline]
+    pub fn checked_add(&self, rhs: &UBig) -> Option<UBig> {
+        if self.bit_len().max(rhs.bit_len()) < UBig::MAX_BIT_LEN {
+            return Some(self + rhs);
+        }
+        // floor((a + b) / 2) never overflows.
+        let half = (self >> 1) + (rhs >> 1) + UBig::from(self.bit(0) && rhs.bit(0));
+        if half.bit_len() >= UBig::MAX_BIT_LEN {
+            None
+        } else {
+            Some((half << 1) + UBig::from(self.bit(0) != rhs.bit(0)))
+        }
+    }
+
+    /// Add a [Word] in place.
+    ///
+    /// `rhs` is a raw machine word, not a decimal digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(_0xffffffffffffffffffffffffffffffff);
+    /// a.add_word(1);
+    /// assert_eq!(a, ubig!(1) << 128);
+    /// ```
+    #[inline]
+    pub fn add_word(&mut self, rhs: Word) {
+        *self = match mem::take(self).into_repr() {
+            Small(word) => UBig::add_word_word(word, rhs),
+            Large(buffer) => UBig::add_large_word(buffer, rhs),
+        }
+    }
+
+    /// Subtract a [Word] in place.
+    ///
+    /// `rhs` is a raw machine word, not a decimal digit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(1) << 128;
+    /// a.sub_word(1);
+    /// assert_eq!(a, ubig!(_0xffffffffffffffffffffffffffffffff));
+    /// ```
+    #[inline]
+    pub fn sub_word(&mut self, rhs: Word) {
+        *self = match mem::take(self).into_repr() {
+            Small(word) => UBig::sub_word_word(word, rhs),
+            Large(buffer) => UBig::sub_large_word(buffer, rhs),
+        }
+    }
+
+    /// Checked subtraction of a [Word].
+    ///
+    /// Returns `None` if the result would be negative. [UBig::sub_word] panics in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).checked_sub_word(3), Some(ubig!(2)));
+    /// assert_eq!(ubig!(0).checked_sub_word(1), None);
+    /// ```
+    #[inline]
+    pub fn checked_sub_word(&self, rhs: Word) -> Option<UBig> {
+        match self.repr() {
+            Small(word) => word.checked_sub(rhs).map(UBig::from_word),
+            Large(buffer) => Some(UBig::sub_large_word(buffer.clone(), rhs)),
+        }
+    }
+
+    /// Subtraction of a [Word] with a borrow flag.
+    ///
+    /// Returns the difference and whether the subtraction underflowed. Underflow is only
+    /// possible when `self` fits in a [Word], and then the result wraps around like
+    /// [Word::overflowing_sub]: it is `self + 2^WORD_BITS - rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig, Word};
+    /// assert_eq!(ubig!(5).overflowing_sub_word(3), (ubig!(2), false));
+    /// assert_eq!(ubig!(0).overflowing_sub_word(1), (UBig::from(Word::MAX), true));
+    /// ```
+    #[inline]
+    pub fn overflowing_sub_word(&self, rhs: Word) -> (UBig, bool) {
+        match self.repr() {
+            Small(word) => {
+                let (res, overflow) = word.overflowing_sub(rhs);
+                (UBig::from_word(res), overflow)
+            }
+            Large(buffer) => (UBig::sub_large_word(buffer.clone(), rhs), false),
+        }
+    }
+
     /// Add two `Word`s.
     #[inline]
-    fn add_word(a: Word, b: Word) -> UBig {
+    fn add_word_word(a: Word, b: Word) -> UBig {
         let (res, overflow) = a.overflowing_add(b);
         if overflow {
             let mut buffer = Buffer::allocate(2);
@@ -555,7 +810,7 @@ impl UBig {
         let overflow = add::add_same_len_in_place(&mut buffer[..n], &rhs[..n]);
         if rhs.len() > n {
             buffer.ensure_capacity(rhs.len());
-            buffer.extend(&rhs[n..]);
+            buffer.extend_from_slice(&rhs[n..]);
         }
         if overflow && add::add_one_in_place(&mut buffer[n..]) {
             buffer.push_may_reallocate(1);
@@ -565,7 +820,7 @@ impl UBig {
 
     /// Subtract two `Word`s.
     #[inline]
-    fn sub_word(a: Word, b: Word) -> UBig {
+    fn sub_word_word(a: Word, b: Word) -> UBig {
         match a.checked_sub(b) {
             Some(res) => UBig::from_word(res),
             None => UBig::panic_negative(),
@@ -578,11 +833,19 @@ impl UBig {
         lhs.into()
     }
 
-    fn sub_large(mut lhs: Buffer, rhs: &[Word]) -> UBig {
+    fn sub_large(lhs: Buffer, rhs: &[Word]) -> UBig {
+        match UBig::checked_sub_large(lhs, rhs) {
+            Some(res) => res,
+            None => UBig::panic_negative(),
+        }
+    }
+
+    fn checked_sub_large(mut lhs: Buffer, rhs: &[Word]) -> Option<UBig> {
         if lhs.len() < rhs.len() || add::sub_in_place(&mut lhs, rhs) {
-            UBig::panic_negative();
+            None
+        } else {
+            Some(lhs.into())
         }
-        lhs.into()
     }
 
     fn sub_large_ref_val(lhs: &[Word], mut rhs: Buffer) -> UBig {
@@ -592,7 +855,7 @@ impl UBig {
         }
         let borrow = add::sub_same_len_in_place_swap(&lhs[..n], &mut rhs);
         rhs.ensure_capacity(lhs.len());
-        rhs.extend(&lhs[n..]);
+        rhs.extend_from_slice(&lhs[n..]);
         if borrow && add::sub_one_in_place(&mut rhs[n..]) {
             UBig::panic_negative();
         }
@@ -1,8 +1,10 @@
 /// Machine word.
-pub(crate) type Word = u32;
+///
+/// Numbers are stored as sequences of `Word`s. Its size depends on the target platform.
+pub type Word = u32;
 
-/// Signed machine word.
-pub(crate) type SignedWord = i32;
+/// Signed machine word, the same size as [Word].
+pub type SignedWord = i32;
 
 /// Double machine word.
 pub(crate) type DoubleWord = u64;
@@ -6,6 +6,8 @@ use crate::arch::word::Word;
 #[inline]
 pub(crate) fn add_with_carry(a: Word, b: Word, carry: bool) -> (Word, bool) {
     let mut sum = 0;
+    // LEGACY: The intrinsic is safe in newer Rust versions, but not in Rust 1.49.
+    #[allow(unused_unsafe)]
     let carry = unsafe { core::arch::x86_64::_addcarry_u64(carry.into(), a, b, &mut sum) };
     (sum, carry != 0)
 }
@@ -16,6 +18,8 @@ pub(crate) fn add_with_carry(a: Word, b: Word, carry: bool) -> (Word, bool) {
 #[inline]
 pub(crate) fn sub_with_borrow(a: Word, b: Word, borrow: bool) -> (Word, bool) {
     let mut diff = 0;
+    // LEGACY: The intrinsic is safe in newer Rust versions, but not in Rust 1.49.
+    #[allow(unused_unsafe)]
     let borrow = unsafe { core::arch::x86_64::_subborrow_u64(borrow.into(), a, b, &mut diff) };
     (diff, borrow != 0)
 }
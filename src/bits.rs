@@ -69,7 +69,7 @@ impl UBig {
         let idx = n / WORD_BITS_USIZE;
         let mut buffer = Buffer::allocate(idx + 1);
         buffer.push(word);
-        buffer.extend((1..idx).map(|_| 0));
+        buffer.push_zeros(idx - 1);
         buffer.push(1 << (n % WORD_BITS_USIZE));
         buffer.into()
     }
@@ -151,6 +151,39 @@ impl UBig {
         panic!("trailing_zeros_large(0)")
     }
 
+    /// Returns the number of ones in the binary representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b101101).count_ones(), 4);
+    /// assert_eq!(ubig!(0).count_ones(), 0);
+    /// ```
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        match self.repr() {
+            Small(word) => word.count_ones() as usize,
+            Large(buffer) => buffer.iter().map(|word| word.count_ones() as usize).sum(),
+        }
+    }
+
+    /// Returns the number of zeros in the binary representation.
+    ///
+    /// Only the significant bits are counted, i.e. the `bit_len()` lowest bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b101101).count_zeros(), 2);
+    /// assert_eq!(ubig!(0).count_zeros(), 0);
+    /// ```
+    #[inline]
+    pub fn count_zeros(&self) -> usize {
+        self.bit_len() - self.count_ones()
+    }
+
     /// Bit length.
     ///
     /// The length of the binary representation of the number.
@@ -202,11 +235,173 @@ impl UBig {
         }
     }
 
+    /// 2 to the power of `exp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::power_of_two(0), ubig!(1));
+    /// assert_eq!(UBig::power_of_two(10), ubig!(1024));
+    /// assert_eq!(UBig::power_of_two(100), ubig!(1) << 100);
+    /// ```
+    #[inline]
+    pub fn power_of_two(exp: usize) -> UBig {
+        if exp < WORD_BITS_USIZE {
+            UBig::from_word(1 << exp)
+        } else {
+            UBig::power_of_two_slow(exp)
+        }
+    }
+
+    fn power_of_two_slow(exp: usize) -> UBig {
+        debug_assert!(exp >= WORD_BITS_USIZE);
+        let idx = exp / WORD_BITS_USIZE;
+        let mut buffer = Buffer::allocate(idx + 1);
+        buffer.push_zeros(idx);
+        buffer.push(1 << (exp % WORD_BITS_USIZE));
+        buffer.into()
+    }
+
     fn is_power_of_two_large(words: &[Word]) -> bool {
         debug_assert!(*words.last().unwrap() != 0);
 
         words[..words.len() - 1].iter().all(|x| *x == 0) && words.last().unwrap().is_power_of_two()
     }
+
+    /// The low `n` bits: `self mod 2^n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b110101).low_bits(3), ubig!(0b101));
+    /// assert_eq!(ubig!(0b110101).low_bits(100), ubig!(0b110101));
+    /// ```
+    pub fn low_bits(&self, n: usize) -> UBig {
+        match self.repr() {
+            Small(word) => {
+                UBig::from_word(word & math::ones::<Word>(n.min(WORD_BITS_USIZE) as u32))
+            }
+            Large(buffer) => {
+                let n_words = n / WORD_BITS_USIZE;
+                if n_words >= buffer.len() {
+                    self.clone()
+                } else {
+                    let mut res = Buffer::allocate(n_words + 1);
+                    res.extend_from_slice(&buffer[..n_words + 1]);
+                    UBig::keep_low_bits_large(res, n)
+                }
+            }
+        }
+    }
+
+    /// Split into the high and low parts at word `n`.
+    ///
+    /// Returns `(self >> (n * WORD_BITS), self mod 2^(n * WORD_BITS))`. The words are copied
+    /// without any shifting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, WORD_BITS};
+    /// let a = (ubig!(3) << WORD_BITS) + ubig!(5);
+    /// assert_eq!(a.split_at_word(1), (ubig!(3), ubig!(5)));
+    /// assert_eq!(a.split_at_word(2), (ubig!(0), a.clone()));
+    /// ```
+    pub fn split_at_word(&self, n: usize) -> (UBig, UBig) {
+        let words = self.as_words();
+        let (low, high) = words.split_at(n.min(words.len()));
+        (UBig::from_words(high), UBig::from_words(low))
+    }
+
+    /// Split into the high and low parts at bit `n`.
+    ///
+    /// Returns `(self >> n, self mod 2^n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b110101).split_at_bit(3), (ubig!(0b110), ubig!(0b101)));
+    /// ```
+    pub fn split_at_bit(&self, n: usize) -> (UBig, UBig) {
+        (self >> n, self.low_bits(n))
+    }
+
+    /// Keep only the low `n` bits in place: `self = self mod 2^n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(0b110101);
+    /// a.keep_low_bits_mut(3);
+    /// assert_eq!(a, ubig!(0b101));
+    /// ```
+    pub fn keep_low_bits_mut(&mut self, n: usize) {
+        *self = match mem::take(self).into_repr() {
+            Small(word) => {
+                UBig::from_word(word & math::ones::<Word>(n.min(WORD_BITS_USIZE) as u32))
+            }
+            Large(buffer) => UBig::keep_low_bits_large(buffer, n),
+        }
+    }
+
+    fn keep_low_bits_large(mut buffer: Buffer, n: usize) -> UBig {
+        let n_words = n / WORD_BITS_USIZE;
+        if n_words < buffer.len() {
+            buffer.truncate(n_words + 1);
+            buffer[n_words] &= math::ones::<Word>((n % WORD_BITS_USIZE) as u32);
+        }
+        buffer.into()
+    }
+
+    /// Rotate left by `bits` within a register of `width` bits.
+    ///
+    /// `width` is the register size, independent of the magnitude of `self`: bits of `self`
+    /// at positions `width` and above are discarded before rotating, and a value with fewer
+    /// than `width` bits is treated as having leading zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b1011).rotate_left(1, 4), ubig!(0b0111));
+    /// assert_eq!(ubig!(0b1).rotate_left(3, 8), ubig!(0b1000));
+    /// assert_eq!(ubig!(0b110001).rotate_left(1, 4), ubig!(0b0010));
+    /// ```
+    pub fn rotate_left(&self, bits: usize, width: usize) -> UBig {
+        if width == 0 {
+            return UBig::from_word(0);
+        }
+        let x = self.low_bits(width);
+        let bits = bits % width;
+        if bits == 0 {
+            return x;
+        }
+        (&x << bits).low_bits(width) | (x >> (width - bits))
+    }
+
+    /// Rotate right by `bits` within a register of `width` bits.
+    ///
+    /// `width` is the register size, independent of the magnitude of `self`: bits of `self`
+    /// at positions `width` and above are discarded before rotating, and a value with fewer
+    /// than `width` bits is treated as having leading zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b1011).rotate_right(1, 4), ubig!(0b1101));
+    /// assert_eq!(ubig!(0b1).rotate_right(1, 8), ubig!(0b10000000));
+    /// ```
+    pub fn rotate_right(&self, bits: usize, width: usize) -> UBig {
+        if width == 0 {
+            return UBig::from_word(0);
+        }
+        self.rotate_left(width - bits % width, width)
+    }
 }
 
 impl IBig {
@@ -229,6 +424,35 @@ impl IBig {
     pub fn trailing_zeros(&self) -> Option<usize> {
         self.magnitude().trailing_zeros()
     }
+
+    /// 2 to the power of `exp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// assert_eq!(IBig::power_of_two(0), ibig!(1));
+    /// assert_eq!(IBig::power_of_two(10), ibig!(1024));
+    /// ```
+    #[inline]
+    pub fn power_of_two(exp: usize) -> IBig {
+        IBig::from(UBig::power_of_two(exp))
+    }
+
+    /// Bit length of the magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(17).bit_len(), 5);
+    /// assert_eq!(ibig!(-17).bit_len(), 5);
+    /// assert_eq!(ibig!(0).bit_len(), 0);
+    /// ```
+    #[inline]
+    pub fn bit_len(&self) -> usize {
+        self.magnitude().bit_len()
+    }
 }
 
 impl NextPowerOfTwo for UBig {
@@ -470,7 +694,7 @@ impl UBig {
         }
         if rhs.len() > buffer.len() {
             buffer.ensure_capacity(rhs.len());
-            buffer.extend(&rhs[buffer.len()..]);
+            buffer.extend_from_slice(&rhs[buffer.len()..]);
         }
         buffer.into()
     }
@@ -567,7 +791,7 @@ impl UBig {
         }
         if rhs.len() > buffer.len() {
             buffer.ensure_capacity(rhs.len());
-            buffer.extend(&rhs[buffer.len()..]);
+            buffer.extend_from_slice(&rhs[buffer.len()..]);
         }
         buffer.into()
     }
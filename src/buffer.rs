@@ -45,6 +45,14 @@ impl Buffer {
         }
     }
 
+    /// Reduce the capacity to the default capacity for the length, if it is larger.
+    #[inline]
+    pub(crate) fn shrink_to_default(&mut self) {
+        if self.capacity() > Buffer::default_capacity(self.len()) {
+            self.reallocate(self.len());
+        }
+    }
+
     /// Change capacity to store `num_words` plus some extra space for future growth.
     ///
     /// # Panics
@@ -81,6 +89,16 @@ impl Buffer {
         self.push(word);
     }
 
+    /// Append a slice of Words.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not enough capacity.
+    pub(crate) fn extend_from_slice(&mut self, words: &[Word]) {
+        assert!(words.len() <= self.capacity() - self.len());
+        self.0.extend_from_slice(words);
+    }
+
     /// Append `n` zeros.
     ///
     /// # Panics
@@ -294,6 +312,35 @@ mod tests {
         assert_eq!(buffer.pop(), None);
     }
 
+    #[test]
+    fn test_extend_from_slice() {
+        let words: Vec<Word> = (1..=20).collect();
+        let mut buffer = Buffer::allocate(25);
+        buffer.push(7);
+        buffer.extend_from_slice(&words);
+        buffer.push_zeros(3);
+        buffer.extend_from_slice(&[]);
+
+        let mut expected = Buffer::allocate(25);
+        expected.push(7);
+        for word in &words {
+            expected.push(*word);
+        }
+        for _ in 0..3 {
+            expected.push(0);
+        }
+        assert_eq!(buffer, expected);
+        assert_eq!(UBig::from(buffer), UBig::from(expected));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_extend_from_slice_too_long() {
+        let mut buffer = Buffer::allocate(2);
+        assert_eq!(buffer.capacity(), 4);
+        buffer.extend_from_slice(&[1, 2, 3, 4, 5]);
+    }
+
     #[test]
     fn test_pop_leading_zeros() {
         let mut buffer = Buffer::allocate(5);
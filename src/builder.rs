@@ -0,0 +1,104 @@
+//! Building a UBig word by word.
+
+use crate::{arch::word::Word, buffer::Buffer, ubig::UBig};
+use core::iter::FromIterator;
+
+/// Builder for a [UBig] from [Word]s, least significant first.
+///
+/// Created by [UBig::with_capacity]. Converting to [UBig] with `into()` removes leading zero
+/// words. If the final length is approximately between `0.9 * words` and `1.125 * words`,
+/// where `words` is the requested capacity, the builder's memory is reused without
+/// reallocation.
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{ubig, UBig, UBigBuilder, WORD_BITS};
+/// let mut builder = UBig::with_capacity(3);
+/// builder.push(5);
+/// builder.push(0);
+/// builder.push(3);
+/// let x: UBig = builder.into();
+/// assert_eq!(x, (ubig!(3) << (2 * WORD_BITS)) + ubig!(5));
+/// ```
+#[derive(Debug)]
+pub struct UBigBuilder {
+    buffer: Buffer,
+}
+
+impl UBig {
+    /// Start building a [UBig] with room for `words` [Word]s.
+    ///
+    /// More words may be pushed, but then the builder may have to reallocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `words` exceeds the maximum length of a [UBig].
+    #[inline]
+    pub fn with_capacity(words: usize) -> UBigBuilder {
+        UBigBuilder {
+            buffer: Buffer::allocate(words),
+        }
+    }
+}
+
+impl UBigBuilder {
+    /// Append the next more significant [Word].
+    #[inline]
+    pub fn push(&mut self, word: Word) {
+        self.buffer.push_may_reallocate(word);
+    }
+
+    /// Number of [Word]s pushed so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Whether no [Word]s have been pushed yet.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Number of [Word]s that can be pushed without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    /// The [Word]s pushed so far.
+    #[inline]
+    pub fn as_words(&self) -> &[Word] {
+        &self.buffer
+    }
+}
+
+impl From<UBigBuilder> for UBig {
+    #[inline]
+    fn from(builder: UBigBuilder) -> UBig {
+        builder.buffer.into()
+    }
+}
+
+impl FromIterator<Word> for UBig {
+    /// Collect [Word]s, least significant first.
+    ///
+    /// Leading zero words are allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig, Word, WORD_BITS};
+    /// let x: UBig = [5 as Word, 0, 3].iter().copied().collect();
+    /// assert_eq!(x, (ubig!(3) << (2 * WORD_BITS)) + ubig!(5));
+    /// ```
+    fn from_iter<T: IntoIterator<Item = Word>>(iter: T) -> UBig {
+        let iter = iter.into_iter();
+        let mut builder = UBig::with_capacity(iter.size_hint().0);
+        for word in iter {
+            builder.push(word);
+        }
+        builder.into()
+    }
+}
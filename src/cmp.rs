@@ -3,6 +3,7 @@
 use crate::{
     arch::word::Word,
     ibig::IBig,
+    primitive::{PrimitiveSigned, PrimitiveUnsigned},
     sign::Sign::*,
     ubig::{Repr::*, UBig},
 };
@@ -49,6 +50,194 @@ impl PartialOrd for IBig {
     }
 }
 
+impl UBig {
+    /// Compare with a [Word].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::cmp::Ordering;
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).cmp_word(7), Ordering::Less);
+    /// assert_eq!(ubig!(_1000000000000000000000).cmp_word(7), Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn cmp_word(&self, rhs: Word) -> Ordering {
+        match self.repr() {
+            Small(word) => word.cmp(&rhs),
+            Large(_) => Ordering::Greater,
+        }
+    }
+
+    /// Compare with a primitive unsigned integer.
+    #[inline]
+    fn cmp_unsigned<T>(&self, rhs: T) -> Ordering
+    where
+        T: PrimitiveUnsigned,
+    {
+        match self.try_to_unsigned::<T>() {
+            Ok(val) => val.cmp(&rhs),
+            Err(_) => Ordering::Greater,
+        }
+    }
+
+    /// Compare with a primitive signed integer.
+    #[inline]
+    fn cmp_signed<T>(&self, rhs: T) -> Ordering
+    where
+        T: PrimitiveSigned,
+    {
+        match rhs.to_sign_magnitude() {
+            (Positive, mag) => self.cmp_unsigned(mag),
+            (Negative, _) => Ordering::Greater,
+        }
+    }
+
+    /// Constant-time equality.
+    ///
+    /// Runs in time that depends only on the number of words in `self` and `other`, not on
+    /// their values. Useful for comparing secrets. Note that the lengths themselves are not
+    /// hidden: other operations, including `==`, [Ord] and arithmetic, are not constant-time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert!(ubig!(_1000000000000000000000).ct_eq(&ubig!(_1000000000000000000000)));
+    /// assert!(!ubig!(5).ct_eq(&ubig!(6)));
+    /// ```
+    pub fn ct_eq(&self, other: &UBig) -> bool {
+        let (lhs, rhs) = (self.as_words(), other.as_words());
+        let n = lhs.len().max(rhs.len());
+        let mut diff: Word = 0;
+        for i in 0..n {
+            diff |= word_at(lhs, i) ^ word_at(rhs, i);
+        }
+        diff == 0
+    }
+
+    /// Constant-time comparison.
+    ///
+    /// Runs in time that depends only on the number of words in `self` and `other`, not on
+    /// their values. See [UBig::ct_eq].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::cmp::Ordering;
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).ct_cmp(&ubig!(6)), Ordering::Less);
+    /// assert_eq!(ubig!(_1000000000000000000000).ct_cmp(&ubig!(6)), Ordering::Greater);
+    /// ```
+    pub fn ct_cmp(&self, other: &UBig) -> Ordering {
+        let (lhs, rhs) = (self.as_words(), other.as_words());
+        let n = lhs.len().max(rhs.len());
+        // -1, 0 or 1: comparison of the words seen so far.
+        // Going from the least significant word, each differing word overrides the result.
+        let mut result: i8 = 0;
+        for i in 0..n {
+            let (a, b) = (word_at(lhs, i), word_at(rhs, i));
+            let less = a.overflowing_sub(b).1 as i8;
+            let greater = b.overflowing_sub(a).1 as i8;
+            let mask = -(less | greater);
+            result = (result & !mask) | ((greater - less) & mask);
+        }
+        result.cmp(&0)
+    }
+}
+
+impl IBig {
+    /// Compare with a primitive unsigned integer.
+    #[inline]
+    fn cmp_unsigned<T>(&self, rhs: T) -> Ordering
+    where
+        T: PrimitiveUnsigned,
+    {
+        match self.sign() {
+            Positive => self.magnitude().cmp_unsigned(rhs),
+            Negative => Ordering::Less,
+        }
+    }
+
+    /// Compare with a primitive signed integer.
+    #[inline]
+    fn cmp_signed<T>(&self, rhs: T) -> Ordering
+    where
+        T: PrimitiveSigned,
+    {
+        let (rhs_sign, rhs_magnitude) = rhs.to_sign_magnitude();
+        match (self.sign(), rhs_sign) {
+            (Positive, Positive) => self.magnitude().cmp_unsigned(rhs_magnitude),
+            (Positive, Negative) => Ordering::Greater,
+            (Negative, Positive) => Ordering::Less,
+            (Negative, Negative) => self.magnitude().cmp_unsigned(rhs_magnitude).reverse(),
+        }
+    }
+}
+
+macro_rules! impl_cmp_with_primitive {
+    ($big:ty, $t:ty, $cmp:ident) => {
+        impl PartialEq<$t> for $big {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                self.$cmp(*other) == Ordering::Equal
+            }
+        }
+
+        impl PartialEq<$big> for $t {
+            #[inline]
+            fn eq(&self, other: &$big) -> bool {
+                other.$cmp(*self) == Ordering::Equal
+            }
+        }
+
+        impl PartialOrd<$t> for $big {
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                Some(self.$cmp(*other))
+            }
+        }
+
+        impl PartialOrd<$big> for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &$big) -> Option<Ordering> {
+                Some(other.$cmp(*self).reverse())
+            }
+        }
+    };
+}
+
+impl_cmp_with_primitive!(UBig, u8, cmp_unsigned);
+impl_cmp_with_primitive!(UBig, u16, cmp_unsigned);
+impl_cmp_with_primitive!(UBig, u32, cmp_unsigned);
+impl_cmp_with_primitive!(UBig, u64, cmp_unsigned);
+impl_cmp_with_primitive!(UBig, u128, cmp_unsigned);
+impl_cmp_with_primitive!(UBig, usize, cmp_unsigned);
+impl_cmp_with_primitive!(UBig, i8, cmp_signed);
+impl_cmp_with_primitive!(UBig, i16, cmp_signed);
+impl_cmp_with_primitive!(UBig, i32, cmp_signed);
+impl_cmp_with_primitive!(UBig, i64, cmp_signed);
+impl_cmp_with_primitive!(UBig, i128, cmp_signed);
+impl_cmp_with_primitive!(UBig, isize, cmp_signed);
+impl_cmp_with_primitive!(IBig, u8, cmp_unsigned);
+impl_cmp_with_primitive!(IBig, u16, cmp_unsigned);
+impl_cmp_with_primitive!(IBig, u32, cmp_unsigned);
+impl_cmp_with_primitive!(IBig, u64, cmp_unsigned);
+impl_cmp_with_primitive!(IBig, u128, cmp_unsigned);
+impl_cmp_with_primitive!(IBig, usize, cmp_unsigned);
+impl_cmp_with_primitive!(IBig, i8, cmp_signed);
+impl_cmp_with_primitive!(IBig, i16, cmp_signed);
+impl_cmp_with_primitive!(IBig, i32, cmp_signed);
+impl_cmp_with_primitive!(IBig, i64, cmp_signed);
+impl_cmp_with_primitive!(IBig, i128, cmp_signed);
+impl_cmp_with_primitive!(IBig, isize, cmp_signed);
+
+/// `words[i]`, or 0 if out of range.
+#[inline]
+fn word_at(words: &[Word], i: usize) -> Word {
+    words.get(i).copied().unwrap_or(0)
+}
+
 /// Compare lhs with rhs as numbers.
 pub(crate) fn cmp_same_len(lhs: &[Word], rhs: &[Word]) -> Ordering {
     assert!(lhs.len() == rhs.len());
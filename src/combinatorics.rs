@@ -0,0 +1,65 @@
+//! Factorials and binomial coefficients.
+
+use crate::ubig::UBig;
+
+/// Ranges up to this length are multiplied sequentially in `product_range`.
+const PRODUCT_RANGE_THRESHOLD: usize = 16;
+
+impl UBig {
+    /// Factorial: `n!`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::factorial(0), ubig!(1));
+    /// assert_eq!(UBig::factorial(20), ubig!(2432902008176640000));
+    /// ```
+    #[inline]
+    pub fn factorial(n: usize) -> UBig {
+        product_range(1, n)
+    }
+
+    /// Binomial coefficient: the number of ways to choose `k` out of `n` elements.
+    ///
+    /// Zero if `k > n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::binomial(5, 2), ubig!(10));
+    /// assert_eq!(UBig::binomial(2, 5), ubig!(0));
+    /// ```
+    pub fn binomial(n: usize, k: usize) -> UBig {
+        if k > n {
+            return UBig::from_word(0);
+        }
+        let k = k.min(n - k);
+        // After step i, res = binomial(n - k + i, i), so the division is exact.
+        let mut res = UBig::from_word(1);
+        for i in 1..=k {
+            res = res * (n - k + i) / i;
+        }
+        res
+    }
+}
+
+/// Product of `lo..=hi`.
+///
+/// Splits the range in half recursively so that the factors being multiplied have similar
+/// lengths.
+fn product_range(lo: usize, hi: usize) -> UBig {
+    if hi < lo {
+        return UBig::from_word(1);
+    }
+    if hi - lo < PRODUCT_RANGE_THRESHOLD {
+        let mut res = UBig::from(lo);
+        for i in lo + 1..=hi {
+            res *= UBig::from(i);
+        }
+        return res;
+    }
+    let mid = lo + (hi - lo) / 2;
+    product_range(lo, mid) * product_range(mid + 1, hi)
+}
@@ -10,7 +10,10 @@ use crate::{
     ubig::{Repr::*, UBig},
 };
 use alloc::vec::Vec;
-use core::convert::{TryFrom, TryInto};
+use core::{
+    convert::{TryFrom, TryInto},
+    iter::FromIterator,
+};
 
 impl Default for UBig {
     /// Default value: 0.
@@ -256,7 +259,120 @@ impl UBig {
     }
 }
 
+impl FromIterator<u8> for UBig {
+    /// Collect little-endian bytes, least significant first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// let x: UBig = [3u8, 2, 1].iter().copied().collect();
+    /// assert_eq!(x, ubig!(0x010203));
+    /// ```
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> UBig {
+        let bytes: Vec<u8> = iter.into_iter().collect();
+        UBig::from_le_bytes(&bytes)
+    }
+}
+
 impl IBig {
+    /// Construct from little-endian two's complement bytes.
+    ///
+    /// An empty slice represents zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// assert_eq!(IBig::from_signed_bytes_le(&[0x01, 0x80]), ibig!(-0x7fff));
+    /// assert_eq!(IBig::from_signed_bytes_le(&[0xff, 0x00]), ibig!(0xff));
+    /// ```
+    pub fn from_signed_bytes_le(bytes: &[u8]) -> IBig {
+        match bytes.last() {
+            Some(&last) if last >= 0x80 => {
+                let inverted: Vec<u8> = bytes.iter().map(|b| !b).collect();
+                -IBig::from(UBig::from_le_bytes(&inverted) + UBig::from_word(1))
+            }
+            _ => IBig::from(UBig::from_le_bytes(bytes)),
+        }
+    }
+
+    /// Construct from big-endian two's complement bytes.
+    ///
+    /// An empty slice represents zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// assert_eq!(IBig::from_signed_bytes_be(&[0x80, 0x01]), ibig!(-0x7fff));
+    /// assert_eq!(IBig::from_signed_bytes_be(&[0x00, 0xff]), ibig!(0xff));
+    /// ```
+    pub fn from_signed_bytes_be(bytes: &[u8]) -> IBig {
+        match bytes.first() {
+            Some(&first) if first >= 0x80 => {
+                let inverted: Vec<u8> = bytes.iter().map(|b| !b).collect();
+                -IBig::from(UBig::from_be_bytes(&inverted) + UBig::from_word(1))
+            }
+            _ => IBig::from(UBig::from_be_bytes(bytes)),
+        }
+    }
+
+    /// Return little-endian two's complement bytes.
+    ///
+    /// The result is the shortest representation that keeps the sign bit, so zero is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert!(ibig!(0).to_signed_bytes_le().is_empty());
+    /// assert_eq!(ibig!(-0x7fff).to_signed_bytes_le(), [0x01, 0x80]);
+    /// assert_eq!(ibig!(0xff).to_signed_bytes_le(), [0xff, 0x00]);
+    /// assert_eq!(ibig!(-1).to_signed_bytes_le(), [0xff]);
+    /// ```
+    pub fn to_signed_bytes_le(&self) -> Vec<u8> {
+        match self.sign() {
+            Positive => {
+                let mut bytes = self.magnitude().to_le_bytes();
+                if bytes.last().map_or(false, |&b| b >= 0x80) {
+                    bytes.push(0);
+                }
+                bytes
+            }
+            Negative => {
+                let mut bytes = (self.magnitude() - UBig::from_word(1)).to_le_bytes();
+                for b in bytes.iter_mut() {
+                    *b = !*b;
+                }
+                if bytes.last().map_or(true, |&b| b < 0x80) {
+                    bytes.push(0xff);
+                }
+                bytes
+            }
+        }
+    }
+
+    /// Return big-endian two's complement bytes.
+    ///
+    /// The result is the shortest representation that keeps the sign bit, so zero is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert!(ibig!(0).to_signed_bytes_be().is_empty());
+    /// assert_eq!(ibig!(-0x7fff).to_signed_bytes_be(), [0x80, 0x01]);
+    /// assert_eq!(ibig!(0xff).to_signed_bytes_be(), [0x00, 0xff]);
+    /// ```
+    #[inline]
+    pub fn to_signed_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_signed_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
     /// Convert to f32.
     ///
     /// Round to nearest, breaking ties to even last bit.
@@ -501,6 +617,47 @@ impl TryFrom<&IBig> for UBig {
     }
 }
 
+/// Convert from f64, rounding toward zero.
+///
+/// The exponent and mantissa are decoded exactly, so every finite value converts to the
+/// integer part of its exact binary value. NaN and infinities are out of bounds.
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{ibig, IBig};
+/// # use core::convert::TryFrom;
+/// assert_eq!(IBig::try_from(-2.75f64), Ok(ibig!(-2)));
+/// assert_eq!(IBig::try_from(1e20f64), Ok(ibig!(100000000000000000000)));
+/// assert!(IBig::try_from(f64::NAN).is_err());
+/// ```
+impl TryFrom<f64> for IBig {
+    type Error = OutOfBoundsError;
+
+    fn try_from(x: f64) -> Result<IBig, OutOfBoundsError> {
+        let bits = x.to_bits();
+        let sign = if bits >> 63 == 0 { Positive } else { Negative };
+        let exponent = ((bits >> 52) & 0x7ff) as usize;
+        let fraction = bits & ((1 << 52) - 1);
+        if exponent == 0x7ff {
+            return Err(OutOfBoundsError);
+        }
+        // Zero and subnormals are below 1, and so are normal numbers with exponent < 1023.
+        // Otherwise |x| = (2^52 + fraction) * 2^(exponent - 1075).
+        let magnitude = if exponent < 1023 {
+            UBig::from_word(0)
+        } else {
+            let mantissa = UBig::from(fraction | (1 << 52));
+            if exponent >= 1075 {
+                mantissa << (exponent - 1075)
+            } else {
+                mantissa >> (1075 - exponent)
+            }
+        };
+        Ok(IBig::from_sign_magnitude(sign, magnitude))
+    }
+}
+
 impl UBig {
     /// Convert an unsigned primitive to [UBig].
     #[inline]
@@ -0,0 +1,318 @@
+//! Conversion to and from digit sequences in an arbitrary radix.
+
+use crate::{
+    arch::word::Word,
+    buffer::Buffer,
+    error::ParseError,
+    mul,
+    ops::DivRem,
+    primitive::{WORD_BITS, WORD_BITS_USIZE},
+    radix::{self, Digit},
+    ubig::UBig,
+};
+use alloc::{vec, vec::Vec};
+
+/// Construct from digits in chunks of `CHUNK_LEN * digits_per_word`.
+const CHUNK_LEN: usize = 256;
+
+impl UBig {
+    /// Iterator over the digits in a given radix, from the least significant to the most
+    /// significant.
+    ///
+    /// Each item is a digit value between 0 and `radix - 1`. There are no leading zeros,
+    /// so zero produces no digits at all.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let digits: Vec<u8> = ubig!(1234).to_radix_digits(10).collect();
+    /// assert_eq!(digits, [4, 3, 2, 1]);
+    /// assert_eq!(ubig!(0).to_radix_digits(10).count(), 0);
+    /// ```
+    #[inline]
+    pub fn to_radix_digits(&self, radix: u32) -> impl Iterator<Item = u8> {
+        radix::check_radix_valid(radix);
+        RadixDigits {
+            remaining: self.clone(),
+            chunk: 0,
+            chunk_digits_left: 0,
+            radix,
+        }
+    }
+
+    /// Digits in a power-of-two radix, from the least significant to the most significant.
+    ///
+    /// Gives the same digits as [UBig::to_radix_digits], but slices them directly out of the
+    /// binary representation in linear time, without division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not a power of 2 between 2 and 32 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0x1f2).to_bits_radix(16), [2, 15, 1]);
+    /// assert_eq!(ubig!(0o17).to_bits_radix(8), [7, 1]);
+    /// assert!(ubig!(0).to_bits_radix(2).is_empty());
+    /// ```
+    pub fn to_bits_radix(&self, radix: u32) -> Vec<u8> {
+        radix::check_radix_valid(radix);
+        assert!(radix.is_power_of_two(), "radix must be a power of 2");
+        let digit_bits = radix.trailing_zeros() as usize;
+        let words = self.as_words();
+        let num_digits = (self.bit_len() + digit_bits - 1) / digit_bits;
+        (0..num_digits)
+            .map(|i| {
+                let (index, shift) = (
+                    i * digit_bits / WORD_BITS_USIZE,
+                    i * digit_bits % WORD_BITS_USIZE,
+                );
+                let mut bits = words[index] >> shift;
+                if shift + digit_bits > WORD_BITS_USIZE && index + 1 < words.len() {
+                    bits |= words[index + 1] << (WORD_BITS_USIZE - shift);
+                }
+                (bits & (radix as Word - 1)) as u8
+            })
+            .collect()
+    }
+
+    /// Number of digits in a given radix.
+    ///
+    /// This is the length of [UBig::to_str_radix], computed without converting. Zero has one
+    /// digit.
+    ///
+    /// Power-of-two radixes are counted from the bit length, radix 10 uses [UBig::ilog10] and
+    /// other radixes use the integer logarithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0).digit_count(10), 1);
+    /// assert_eq!(ubig!(999).digit_count(10), 3);
+    /// assert_eq!(ubig!(1000).digit_count(10), 4);
+    /// assert_eq!(ubig!(256).digit_count(16), 3);
+    /// ```
+    pub fn digit_count(&self, radix: u32) -> usize {
+        radix::check_radix_valid(radix);
+        if *self == 0u8 {
+            1
+        } else if radix.is_power_of_two() {
+            let digit_bits = radix.trailing_zeros() as usize;
+            (self.bit_len() + digit_bits - 1) / digit_bits
+        } else if radix == 10 {
+            self.ilog10() + 1
+        } else {
+            self.ilog(&UBig::from_word(radix as Word)) + 1
+        }
+    }
+
+    /// Construct from digits in a given radix, from the least significant to the most
+    /// significant.
+    ///
+    /// Each digit is a value between 0 and `radix - 1`. An empty slice represents zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ParseError::DigitOutOfRange] with the first digit that is not less than
+    /// `radix` and its index in `digits`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{error::ParseError, ubig, UBig};
+    /// assert_eq!(UBig::from_radix_digits(&[4, 3, 2, 1], 10)?, ubig!(1234));
+    /// assert_eq!(
+    ///     UBig::from_radix_digits(&[0, 10], 10),
+    ///     Err(ParseError::DigitOutOfRange { digit: 10, position: 1 })
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_radix_digits(digits: &[u8], radix: u32) -> Result<UBig, ParseError> {
+        radix::check_radix_valid(radix);
+        if let Some(position) = digits.iter().position(|&digit| digit as Digit >= radix) {
+            return Err(ParseError::DigitOutOfRange {
+                digit: digits[position],
+                position,
+            });
+        }
+        if radix.is_power_of_two() {
+            Ok(from_digits_power_two(digits, radix))
+        } else {
+            Ok(from_digits_non_power_two(digits, radix))
+        }
+    }
+}
+
+/// Construct from valid digits in a power-of-two radix, least significant first.
+fn from_digits_power_two(digits: &[u8], radix: Digit) -> UBig {
+    debug_assert!(radix::is_radix_valid(radix) && radix.is_power_of_two());
+
+    let log_radix = radix.trailing_zeros();
+    #[allow(clippy::redundant_closure)]
+    let num_bits = digits
+        .len()
+        .checked_mul(log_radix as usize)
+        .unwrap_or_else(|| UBig::panic_number_too_large());
+    let mut buffer = Buffer::allocate((num_bits + WORD_BITS_USIZE - 1) / WORD_BITS_USIZE);
+    let mut bits = 0;
+    let mut word = 0;
+    for &digit in digits {
+        word |= (digit as Word) << bits;
+        let new_bits = bits + log_radix;
+        if new_bits >= WORD_BITS {
+            buffer.push(word);
+            word = (digit as Word) >> (WORD_BITS - bits);
+            bits = new_bits - WORD_BITS;
+        } else {
+            bits = new_bits;
+        }
+    }
+    if bits > 0 {
+        buffer.push(word);
+    }
+    buffer.into()
+}
+
+/// Construct from valid digits in a non-power-of-two radix, least significant first.
+fn from_digits_non_power_two(digits: &[u8], radix: Digit) -> UBig {
+    debug_assert!(radix::is_radix_valid(radix) && !radix.is_power_of_two());
+    let radix_info = radix::radix_info(radix);
+    let chunk_digits = CHUNK_LEN * radix_info.digits_per_word;
+
+    if digits.len() <= chunk_digits {
+        return from_digits_chunk(digits, radix);
+    }
+
+    // Calculate radix^(chunk_digits<<i).
+    let mut radix_powers = vec![UBig::from_word(radix_info.range_per_word).pow(CHUNK_LEN)];
+
+    // while (chunk_digits << radix_powers.len()) < digits.len()
+    // To avoid overflow:
+    while chunk_digits <= (digits.len() - 1) >> radix_powers.len() {
+        let prev = radix_powers.last().unwrap();
+        let new = prev * prev;
+        radix_powers.push(new);
+    }
+
+    from_digits_divide_conquer(digits, radix, chunk_digits, &radix_powers)
+}
+
+/// Construct from valid digits in a non-power-of-two radix, least significant first, one
+/// `Word`-sized group of digits at a time.
+///
+/// The number of digits is limited to `CHUNK_LEN * digits_per_word`.
+fn from_digits_chunk(digits: &[u8], radix: Digit) -> UBig {
+    let radix_info = radix::radix_info(radix);
+    debug_assert!(digits.len() <= CHUNK_LEN * radix_info.digits_per_word);
+
+    let groups = digits.chunks(radix_info.digits_per_word);
+    let mut buffer = Buffer::allocate(groups.len());
+    for group in groups.rev() {
+        let next = group.iter().rev().fold(0, |word: Word, &digit| {
+            word * (radix as Word) + (digit as Word)
+        });
+        let carry = mul::mul_word_in_place_with_carry(&mut buffer, radix_info.range_per_word, next);
+        if carry != 0 {
+            buffer.push(carry);
+        }
+    }
+    buffer.into()
+}
+
+/// Construct from valid digits in a non-power-of-two radix, least significant first.
+///
+/// `radix_powers` contains radix^n for n = chunk_digits << i
+fn from_digits_divide_conquer(
+    digits: &[u8],
+    radix: Digit,
+    chunk_digits: usize,
+    radix_powers: &[UBig],
+) -> UBig {
+    debug_assert!(digits.len() <= chunk_digits << radix_powers.len());
+
+    match radix_powers.split_last() {
+        None => from_digits_chunk(digits, radix),
+        Some((radix_power, radix_powers)) => {
+            let digits_lo_len = chunk_digits << radix_powers.len();
+            if digits.len() <= digits_lo_len {
+                from_digits_divide_conquer(digits, radix, chunk_digits, radix_powers)
+            } else {
+                let (digits_lo, digits_hi) = digits.split_at(digits_lo_len);
+                let res_hi =
+                    from_digits_divide_conquer(digits_hi, radix, chunk_digits, radix_powers);
+                let res_lo =
+                    from_digits_divide_conquer(digits_lo, radix, chunk_digits, radix_powers);
+                res_hi * radix_power + res_lo
+            }
+        }
+    }
+}
+
+/// Iterator over digits, least significant first.
+///
+/// Digits are extracted from `remaining` one `Word`-sized chunk at a time.
+struct RadixDigits {
+    remaining: UBig,
+    chunk: Word,
+    chunk_digits_left: usize,
+    radix: Digit,
+}
+
+impl RadixDigits {
+    /// Split off the next chunk of `digits_per_word` digits from `remaining`.
+    fn next_chunk(&mut self) {
+        let digits_per_word = radix::radix_info(self.radix).digits_per_word;
+        if self.radix.is_power_of_two() {
+            let bits = digits_per_word * self.radix.trailing_zeros() as usize;
+            let low_word = self.remaining.as_words().first().copied().unwrap_or(0);
+            self.chunk = if bits == WORD_BITS_USIZE {
+                low_word
+            } else {
+                low_word & ((1 << bits) - 1)
+            };
+            self.remaining >>= bits;
+        } else {
+            let range_per_word = radix::radix_info(self.radix).range_per_word;
+            let (quotient, chunk) = (&self.remaining).div_rem(range_per_word);
+            self.remaining = quotient;
+            self.chunk = chunk;
+        }
+        self.chunk_digits_left = digits_per_word;
+    }
+}
+
+impl Iterator for RadixDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.chunk_digits_left == 0 {
+            if self.remaining == UBig::from_word(0) {
+                return None;
+            }
+            self.next_chunk();
+        }
+        if self.chunk == 0 && self.remaining == UBig::from_word(0) {
+            return None;
+        }
+        let digit = (self.chunk % self.radix as Word) as u8;
+        self.chunk /= self.radix as Word;
+        self.chunk_digits_left -= 1;
+        Some(digit)
+    }
+}
@@ -0,0 +1,51 @@
+//! Exact division (Jebelean's algorithm).
+//!
+//! When the remainder is known to be zero, the quotient can be computed from the least
+//! significant word up, one word at a time, by multiplying with the inverse of the divisor
+//! modulo 2^WORD_BITS. No quotient estimation or normalization is needed.
+
+use crate::{
+    add,
+    arch::word::Word,
+    math, mul,
+    primitive::{extend_word, split_double_word},
+};
+
+/// words = words / rhs, assuming the division is exact.
+///
+/// rhs must be odd.
+pub(crate) fn div_exact_by_word_in_place(words: &mut [Word], rhs: Word) {
+    debug_assert!(rhs & 1 == 1);
+    let inv = math::inverse_word(rhs);
+    let mut borrow = 0;
+    for word in words.iter_mut() {
+        let (diff, overflow) = word.overflowing_sub(borrow);
+        let q = diff.wrapping_mul(inv);
+        // q * rhs = diff + hi * 2^WORD_BITS
+        let (_, hi) = split_double_word(extend_word(q) * extend_word(rhs));
+        *word = q;
+        borrow = hi + Word::from(overflow);
+    }
+}
+
+/// words[..words.len() - rhs.len() + 1] = words / rhs, assuming the division is exact.
+///
+/// The rest of `words` is left in an unspecified state.
+///
+/// rhs must have at least 2 words, be odd, and be no longer than `words`.
+pub(crate) fn div_exact_in_place(words: &mut [Word], rhs: &[Word]) {
+    debug_assert!(rhs.len() >= 2 && rhs[0] & 1 == 1 && words.len() >= rhs.len());
+    let quotient_len = words.len() - rhs.len() + 1;
+    let inv = math::inverse_word(rhs[0]);
+    for i in 0..quotient_len {
+        let q = words[i].wrapping_mul(inv);
+        // Only the quotient words are needed, so higher words are not updated.
+        let end = quotient_len.min(i + rhs.len());
+        let borrow = mul::sub_mul_word_same_len_in_place(&mut words[i..end], q, &rhs[..end - i]);
+        if end < quotient_len {
+            let _overflow = add::sub_word_in_place(&mut words[end..quotient_len], borrow);
+        }
+        debug_assert!(words[i] == 0);
+        words[i] = q;
+    }
+}
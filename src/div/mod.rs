@@ -10,8 +10,11 @@ use crate::{
 use alloc::alloc::Layout;
 
 mod divide_conquer;
+mod exact;
 mod simple;
 
+pub(crate) use exact::{div_exact_by_word_in_place, div_exact_in_place};
+
 /// If divisor or quotient is at most this length, use the simple division algorithm.
 const MAX_LEN_SIMPLE: usize = 32;
 
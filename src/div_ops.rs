@@ -216,7 +216,7 @@ impl DivRem<UBig> for UBig {
     #[inline]
     fn div_rem(self, rhs: UBig) -> (UBig, UBig) {
         match (self.into_repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::div_rem_word(word0, word1),
+            (Small(word0), Small(word1)) => UBig::div_rem_word_word(word0, word1),
             (Small(word0), Large(_)) => (UBig::from_word(0), UBig::from_word(word0)),
             (Large(buffer0), Small(word1)) => UBig::div_rem_large_word(buffer0, word1),
             (Large(buffer0), Large(buffer1)) => {
@@ -237,7 +237,7 @@ impl DivRem<&UBig> for UBig {
     #[inline]
     fn div_rem(self, rhs: &UBig) -> (UBig, UBig) {
         match (self.into_repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::div_rem_word(word0, *word1),
+            (Small(word0), Small(word1)) => UBig::div_rem_word_word(word0, *word1),
             (Small(word0), Large(_)) => (UBig::from_word(0), UBig::from_word(word0)),
             (Large(buffer0), Small(word1)) => UBig::div_rem_large_word(buffer0, *word1),
             (Large(buffer0), Large(buffer1)) => {
@@ -258,7 +258,7 @@ impl DivRem<UBig> for &UBig {
     #[inline]
     fn div_rem(self, rhs: UBig) -> (UBig, UBig) {
         match (self.repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::div_rem_word(*word0, word1),
+            (Small(word0), Small(word1)) => UBig::div_rem_word_word(*word0, word1),
             (Small(word0), Large(_)) => (UBig::from_word(0), UBig::from_word(*word0)),
             (Large(buffer0), Small(word1)) => UBig::div_rem_large_word(buffer0.clone(), word1),
             (Large(buffer0), Large(mut buffer1)) => {
@@ -281,7 +281,7 @@ impl DivRem<&UBig> for &UBig {
     #[inline]
     fn div_rem(self, rhs: &UBig) -> (UBig, UBig) {
         match (self.repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::div_rem_word(*word0, *word1),
+            (Small(word0), Small(word1)) => UBig::div_rem_word_word(*word0, *word1),
             (Small(word0), Large(_)) => (UBig::from_word(0), UBig::from_word(*word0)),
             (Large(buffer0), Small(word1)) => UBig::div_rem_large_word(buffer0.clone(), *word1),
             (Large(buffer0), Large(buffer1)) => {
@@ -1296,9 +1296,154 @@ impl UBig {
         }
     }
 
+    /// Whether `self` is a multiple of `rhs`.
+    ///
+    /// Only zero is a multiple of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert!(ubig!(12).is_multiple_of(&ubig!(4)));
+    /// assert!(!ubig!(12).is_multiple_of(&ubig!(5)));
+    /// assert!(ubig!(0).is_multiple_of(&ubig!(0)));
+    /// ```
+    pub fn is_multiple_of(&self, rhs: &UBig) -> bool {
+        match (self.repr(), rhs.repr()) {
+            (_, Small(0)) => *self == UBig::from_word(0),
+            (Small(word), Small(rhs_word)) => word % rhs_word == 0,
+            (Small(word), Large(_)) => *word == 0,
+            (Large(buffer), Small(rhs_word)) => div::rem_by_word(buffer, *rhs_word) == 0,
+            (Large(buffer), Large(rhs_buffer)) => {
+                buffer.len() >= rhs_buffer.len() && self % rhs == UBig::from_word(0)
+            }
+        }
+    }
+
+    /// Divide by a [Word] in place, returning the remainder.
+    ///
+    /// `rhs` is a raw machine word, not a decimal digit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(_1000000000000000000000000000007);
+    /// assert_eq!(a.div_rem_word(10), 7);
+    /// assert_eq!(a, ubig!(_100000000000000000000000000000));
+    /// ```
+    #[inline]
+    pub fn div_rem_word(&mut self, rhs: Word) -> Word {
+        match mem::take(self).into_repr() {
+            Small(word) => match word.checked_div(rhs) {
+                Some(q) => {
+                    *self = UBig::from_word(q);
+                    word % rhs
+                }
+                None => panic_divide_by_0(),
+            },
+            Large(mut buffer) => {
+                if rhs == 0 {
+                    panic_divide_by_0();
+                }
+                let rem = div::div_by_word_in_place(&mut buffer, rhs);
+                *self = buffer.into();
+                rem
+            }
+        }
+    }
+
+    /// Exact division: `self / rhs` where `rhs` is known to divide `self`.
+    ///
+    /// Faster than `/` because the quotient is computed from the least significant word up,
+    /// without estimating quotient digits. The result is unspecified if the division is not
+    /// exact; debug builds check for this.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let a = ubig!(_123456789012345678901234567890);
+    /// let b = ubig!(_987654321987654321);
+    /// assert_eq!((&a * &b).div_exact(&b), a);
+    /// ```
+    pub fn div_exact(&self, rhs: &UBig) -> UBig {
+        debug_assert!(self.is_multiple_of(rhs), "division is not exact");
+        match (self.repr(), rhs.repr()) {
+            (_, Small(word)) => self.div_exact_word(*word),
+            (Small(_), Large(_)) => UBig::from_word(0),
+            (Large(_), Large(_)) => {
+                // Remove common factors of 2 so that the divisor is odd.
+                let shift = rhs.trailing_zeros().unwrap();
+                let (lhs, rhs) = (self >> shift, rhs >> shift);
+                match rhs.repr() {
+                    Small(word) => lhs.div_exact_word(*word),
+                    Large(rhs_buffer) => match lhs.into_repr() {
+                        Small(_) => UBig::from_word(0),
+                        Large(buffer) => UBig::div_exact_large(buffer, rhs_buffer),
+                    },
+                }
+            }
+        }
+    }
+
+    /// Exact division by a [Word]: `self / rhs` where `rhs` is known to divide `self`.
+    ///
+    /// `rhs` is a raw machine word, not a decimal digit. The result is unspecified if the
+    /// division is not exact; debug builds check for this.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(_1000000000000000000000000000000).div_exact_word(10),
+    ///            ubig!(_100000000000000000000000000000));
+    /// ```
+    pub fn div_exact_word(&self, rhs: Word) -> UBig {
+        if rhs == 0 {
+            panic_divide_by_0();
+        }
+        match self.repr() {
+            Small(word) => {
+                debug_assert!(word % rhs == 0, "division is not exact");
+                UBig::from_word(word / rhs)
+            }
+            Large(buffer) => {
+                debug_assert!(div::rem_by_word(buffer, rhs) == 0, "division is not exact");
+                let mut buffer = buffer.clone();
+                let shift = rhs.trailing_zeros();
+                let _rem = shift::shr_in_place(&mut buffer, shift);
+                div::div_exact_by_word_in_place(&mut buffer, rhs >> shift);
+                buffer.into()
+            }
+        }
+    }
+
+    /// `lhs / rhs` for an odd `rhs` that divides `lhs`.
+    fn div_exact_large(mut lhs: Buffer, rhs: &[Word]) -> UBig {
+        if lhs.len() < rhs.len() {
+            return UBig::from_word(0);
+        }
+        div::div_exact_in_place(&mut lhs, rhs);
+        lhs.truncate(lhs.len() - rhs.len() + 1);
+        lhs.into()
+    }
+
     /// (lhs / rhs, lhs % rhs)
     #[inline]
-    fn div_rem_word(lhs: Word, rhs: Word) -> (UBig, UBig) {
+    fn div_rem_word_word(lhs: Word, rhs: Word) -> (UBig, UBig) {
         // If division works, remainder also works.
         match lhs.checked_div(rhs) {
             Some(res) => (UBig::from_word(res), UBig::from_word(lhs % rhs)),
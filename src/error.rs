@@ -17,21 +17,70 @@ impl std::error::Error for OutOfBoundsError {}
 
 /// Error parsing a number.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum ParseError {
     /// No digits in the string.
     NoDigits,
     /// Invalid digit for a given radix.
-    InvalidDigit,
+    InvalidDigit {
+        /// The offending character.
+        character: char,
+        /// Byte offset of the character in the input.
+        position: usize,
+    },
+    /// Misplaced `_` separator: leading, trailing or doubled.
+    InvalidSeparator {
+        /// Byte offset of the separator in the input.
+        position: usize,
+    },
+    /// The number exceeds the requested maximum length.
+    TooLarge,
+    /// Digit value not less than the radix, in a slice of digit values.
+    DigitOutOfRange {
+        /// The offending digit value.
+        digit: u8,
+        /// Index of the digit in the input.
+        position: usize,
+    },
 }
 
 impl Display for ParseError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             ParseError::NoDigits => f.write_str("no digits"),
-            ParseError::InvalidDigit => f.write_str("invalid digit"),
+            ParseError::InvalidDigit {
+                character,
+                position,
+            } => write!(f, "invalid digit {:?} at position {}", character, position),
+            ParseError::InvalidSeparator { position } => {
+                write!(f, "invalid separator at position {}", position)
+            }
+            ParseError::TooLarge => f.write_str("number too large"),
+            ParseError::DigitOutOfRange { digit, position } => {
+                write!(f, "digit {} out of range at position {}", digit, position)
+            }
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for ParseError {}
+
+/// Error in modular arithmetic.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ModError {
+    /// The modulus is zero.
+    ZeroModulus,
+}
+
+impl Display for ModError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ModError::ZeroModulus => f.write_str("zero modulus"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ModError {}
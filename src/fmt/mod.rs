@@ -2,12 +2,15 @@
 
 use crate::{
     ibig::IBig,
+    ops::DivRem,
     radix::{self, Digit, DigitCase},
     sign::Sign::{self, *},
     ubig::UBig,
 };
-use core::fmt::{
-    self, Alignment, Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex, Write,
+use alloc::string::{String, ToString};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Alignment, Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex, Write},
 };
 use digit_writer::DigitWriter;
 
@@ -28,9 +31,16 @@ impl Display for UBig {
     }
 }
 
+/// Numbers with more bits than this are shown by `Debug` as a [UBig::debug_summary].
+const DEBUG_MAX_FULL_BITS: usize = 10000;
+
 impl Debug for UBig {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(self, f)
+        if self.bit_len() > DEBUG_MAX_FULL_BITS {
+            f.write_str(&self.debug_summary())
+        } else {
+            Display::fmt(self, f)
+        }
     }
 }
 
@@ -101,7 +111,11 @@ impl Display for IBig {
 
 impl Debug for IBig {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(self, f)
+        if self.bit_len() > DEBUG_MAX_FULL_BITS {
+            f.write_str(&self.debug_summary())
+        } else {
+            Display::fmt(self, f)
+        }
     }
 }
 
@@ -172,7 +186,7 @@ impl UBig {
     /// assert_eq!(format!("{:+010}", ubig!(35).in_radix(36)), "+00000000z");
     /// ```
     #[inline]
-    pub fn in_radix(&self, radix: u32) -> InRadix {
+    pub fn in_radix(&self, radix: u32) -> InRadix<'_> {
         radix::check_radix_valid(radix);
         InRadix {
             sign: Positive,
@@ -180,6 +194,149 @@ impl UBig {
             radix,
         }
     }
+
+    /// Convert to a string in a given radix.
+    ///
+    /// Digits 10-35 are written as lower-case letters a-z.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// let x = ubig!(1234567);
+    /// assert_eq!(x.to_str_radix(32), "15lk7");
+    /// assert_eq!(UBig::from_str_radix(&x.to_str_radix(32), 32).unwrap(), x);
+    /// ```
+    #[inline]
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        self.in_radix(radix).to_string()
+    }
+
+    /// Convert to a string in a given radix, using upper-case letters.
+    ///
+    /// Digits 10-35 are written as upper-case letters A-Z.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(1234567).to_str_radix_uppercase(32), "15LK7");
+    /// ```
+    #[inline]
+    pub fn to_str_radix_uppercase(&self, radix: u32) -> String {
+        alloc::format!("{:#}", self.in_radix(radix))
+    }
+
+    /// Convert to a decimal string with `separator` between groups of `group_size` digits,
+    /// counting from the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `group_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(1234567).to_string_grouped(3, ','), "1,234,567");
+    /// assert_eq!(ubig!(123).to_string_grouped(3, ','), "123");
+    /// ```
+    #[inline]
+    pub fn to_string_grouped(&self, group_size: usize, separator: char) -> String {
+        group_digits("", &self.to_string(), group_size, separator)
+    }
+
+    /// Convert to a decimal string in scientific notation with `sig_digits` significant
+    /// digits, such as `1.2345e100`.
+    ///
+    /// The significand is rounded to nearest, with ties to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sig_digits` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(123456).to_scientific(3), "1.23e5");
+    /// assert_eq!(ubig!(9996).to_scientific(3), "1.00e4");
+    /// assert_eq!(ubig!(7).to_scientific(1), "7e0");
+    /// ```
+    pub fn to_scientific(&self, sig_digits: usize) -> String {
+        assert!(
+            sig_digits != 0,
+            "number of significant digits must be positive"
+        );
+        let (significand, exp) = if *self == 0u8 {
+            (UBig::from_word(0), 0)
+        } else {
+            let exp = self.ilog10();
+            if exp < sig_digits {
+                (self * UBig::from_word(10).pow(sig_digits - 1 - exp), exp)
+            } else {
+                let divisor = UBig::from_word(10).pow(exp + 1 - sig_digits);
+                let (mut q, r) = self.div_rem(&divisor);
+                let double_r = r << 1;
+                if double_r > divisor || (double_r == divisor && q.bit(0)) {
+                    q += UBig::from_word(1);
+                }
+                if q.ilog10() == sig_digits {
+                    // Rounded up to the next power of 10.
+                    (q / UBig::from_word(10), exp + 1)
+                } else {
+                    (q, exp)
+                }
+            }
+        };
+        let digits = alloc::format!("{:0width$}", significand, width = sig_digits);
+        let (first, rest) = digits.split_at(1);
+        if rest.is_empty() {
+            alloc::format!("{}e{}", first, exp)
+        } else {
+            alloc::format!("{}.{}e{}", first, rest, exp)
+        }
+    }
+
+    /// A short description of the number, for debugging large values.
+    ///
+    /// Shows the number of words, the approximate number of decimal digits, and the lowest 8
+    /// hexadecimal digits. `Debug` formatting uses this for numbers longer than 10000 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let x = (ubig!(1) << 1000) + ubig!(0xabcd);
+    /// let summary = x.debug_summary();
+    /// assert!(summary.starts_with("UBig("));
+    /// assert!(summary.ends_with(" words, ~302 decimal digits, 0x...0000abcd at bottom)"));
+    /// ```
+    pub fn debug_summary(&self) -> String {
+        self.debug_summary_with_prefix("UBig(")
+    }
+
+    fn debug_summary_with_prefix(&self, prefix: &str) -> String {
+        // Estimate the number of digits from the bit length rather than counting them.
+        // log10(2) is just below 1292913986 / 2^32.
+        let digits = ((self.bit_len() as u128 * 1292913986 + 0xffffffff) >> 32).max(1);
+        let low = u32::try_from(&self.low_bits(32)).unwrap();
+        alloc::format!(
+            "{}{} words, ~{} decimal digits, 0x...{:08x} at bottom)",
+            prefix,
+            self.as_words().len(),
+            digits,
+            low
+        )
+    }
 }
 
 impl IBig {
@@ -197,7 +354,7 @@ impl IBig {
     /// assert_eq!(format!("{:010}", ibig!(-35).in_radix(36)), "-00000000z");
     /// ```
     #[inline]
-    pub fn in_radix(&self, radix: u32) -> InRadix {
+    pub fn in_radix(&self, radix: u32) -> InRadix<'_> {
         radix::check_radix_valid(radix);
         InRadix {
             sign: self.sign(),
@@ -205,6 +362,132 @@ impl IBig {
             radix,
         }
     }
+
+    /// Convert to a string in a given radix.
+    ///
+    /// Digits 10-35 are written as lower-case letters a-z.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// let x = ibig!(-1234567);
+    /// assert_eq!(x.to_str_radix(32), "-15lk7");
+    /// assert_eq!(IBig::from_str_radix(&x.to_str_radix(32), 32).unwrap(), x);
+    /// ```
+    #[inline]
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        self.in_radix(radix).to_string()
+    }
+
+    /// Convert to a string in a given radix, using upper-case letters.
+    ///
+    /// Digits 10-35 are written as upper-case letters A-Z.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-1234567).to_str_radix_uppercase(32), "-15LK7");
+    /// ```
+    #[inline]
+    pub fn to_str_radix_uppercase(&self, radix: u32) -> String {
+        alloc::format!("{:#}", self.in_radix(radix))
+    }
+
+    /// Convert to a decimal string with `separator` between groups of `group_size` digits,
+    /// counting from the right.
+    ///
+    /// The sign, if any, comes before the first group.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `group_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-1234567).to_string_grouped(3, ','), "-1,234,567");
+    /// ```
+    #[inline]
+    pub fn to_string_grouped(&self, group_size: usize, separator: char) -> String {
+        let sign = match self.sign() {
+            Positive => "",
+            Negative => "-",
+        };
+        group_digits(sign, &self.magnitude().to_string(), group_size, separator)
+    }
+
+    /// Convert to a decimal string in scientific notation with `sig_digits` significant
+    /// digits, such as `-1.2345e100`.
+    ///
+    /// The significand is rounded to nearest, with ties to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sig_digits` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-123456).to_scientific(3), "-1.23e5");
+    /// ```
+    pub fn to_scientific(&self, sig_digits: usize) -> String {
+        let magnitude = self.magnitude().to_scientific(sig_digits);
+        match self.sign() {
+            Positive => magnitude,
+            Negative => alloc::format!("-{}", magnitude),
+        }
+    }
+
+    /// A short description of the number, for debugging large values.
+    ///
+    /// Like [UBig::debug_summary], with the sign in front of the number of words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// let x = -(ibig!(1) << 1000) - ibig!(0xabcd);
+    /// let summary = x.debug_summary();
+    /// assert!(summary.starts_with("IBig(-"));
+    /// assert!(summary.ends_with(" words, ~302 decimal digits, 0x...0000abcd at bottom)"));
+    /// ```
+    pub fn debug_summary(&self) -> String {
+        let prefix = match self.sign() {
+            Positive => "IBig(",
+            Negative => "IBig(-",
+        };
+        self.magnitude().debug_summary_with_prefix(prefix)
+    }
+}
+
+/// `sign` followed by `digits` with `separator` between groups of `group_size` digits.
+fn group_digits(sign: &str, digits: &str, group_size: usize, separator: char) -> String {
+    assert!(group_size != 0, "group size must be positive");
+    let num_separators = (digits.len() - 1) / group_size;
+    let mut res =
+        String::with_capacity(sign.len() + digits.len() + num_separators * separator.len_utf8());
+    res.push_str(sign);
+    // All digits are ASCII, so byte indices are character boundaries.
+    let mut start = digits.len() - num_separators * group_size;
+    res.push_str(&digits[..start]);
+    while start < digits.len() {
+        res.push(separator);
+        res.push_str(&digits[start..start + group_size]);
+        start += group_size;
+    }
+    res
 }
 
 /// Representation of a [UBig] or [IBig] in any radix between 2 and 36 inclusive.
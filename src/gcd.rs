@@ -1,6 +1,12 @@
 //! Greatest common divisor.
 
-use crate::{ibig::IBig, ops::DivRem, ubig::UBig};
+use crate::{
+    arch::word::Word,
+    ibig::IBig,
+    ops::{DivRem, RemEuclid, UnsignedAbs},
+    primitive::WORD_BITS,
+    ubig::UBig,
+};
 use core::mem;
 
 impl UBig {
@@ -17,8 +23,15 @@ impl UBig {
     ///
     /// `ubig!(0).gcd(&ubig!(0))` panics.
     pub fn gcd(&self, rhs: &UBig) -> UBig {
-        let (mut a, mut b) = (self.clone(), rhs.clone());
+        if self.as_words().len().min(rhs.as_words().len()) >= LEHMER_THRESHOLD {
+            UBig::gcd_lehmer(self.clone(), rhs.clone())
+        } else {
+            UBig::gcd_binary(self.clone(), rhs.clone())
+        }
+    }
 
+    /// Binary GCD, after one round of the Euclidean algorithm.
+    fn gcd_binary(mut a: UBig, mut b: UBig) -> UBig {
         let zeros = match (a.trailing_zeros(), b.trailing_zeros()) {
             (None, None) => panic!("gcd(0, 0)"),
             (None, Some(_)) => return b,
@@ -54,6 +67,82 @@ impl UBig {
         b << zeros
     }
 
+    /// Lehmer's GCD.
+    ///
+    /// Each step runs the Euclidean algorithm on the leading words of `a` and `b` only,
+    /// accumulating the quotients in a 2x2 matrix of single-word cofactors, and then applies
+    /// the matrix to the full numbers at once.
+    ///
+    /// `a` and `b` must be nonzero.
+    fn gcd_lehmer(mut a: UBig, mut b: UBig) -> UBig {
+        if a < b {
+            mem::swap(&mut a, &mut b);
+        }
+        while b.as_words().len() > 1 {
+            let step = LehmerStep::simulate(a.as_words(), b.as_words());
+            if step.v0 == 0 {
+                // No progress on the leading words: do a full Euclidean step.
+                let r = &a % &b;
+                a = mem::replace(&mut b, r);
+            } else if step.even {
+                let new_a = &a * step.u0 - &b * step.v0;
+                b = &b * step.v1 - &a * step.u1;
+                a = new_a;
+            } else {
+                let new_a = &b * step.v0 - &a * step.u0;
+                b = &a * step.u1 - &b * step.v1;
+                a = new_a;
+            }
+        }
+        if b == 0u8 {
+            a
+        } else {
+            UBig::gcd_binary(a, b)
+        }
+    }
+
+    /// Least common multiple.
+    ///
+    /// `lcm(0, x)` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(12).lcm(&ubig!(18)), ubig!(36));
+    /// assert_eq!(ubig!(0).lcm(&ubig!(18)), ubig!(0));
+    /// ```
+    pub fn lcm(&self, rhs: &UBig) -> UBig {
+        if *self == 0u8 || *rhs == 0u8 {
+            return UBig::from_word(0);
+        }
+        self.gcd_lcm(rhs).1
+    }
+
+    /// Greatest common divisor and least common multiple.
+    ///
+    /// The gcd is computed once and the lcm is derived from it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(12).gcd_lcm(&ubig!(18)), (ubig!(6), ubig!(36)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `ubig!(0).gcd_lcm(&ubig!(0))` panics.
+    pub fn gcd_lcm(&self, rhs: &UBig) -> (UBig, UBig) {
+        let g = self.gcd(rhs);
+        if *self == 0u8 || *rhs == 0u8 {
+            return (g, UBig::from_word(0));
+        }
+        // Divide first to keep the intermediate value small.
+        let lcm = self / &g * rhs;
+        (g, lcm)
+    }
+
     /// Greatest common divisors and the Bézout coefficients.
     ///
     /// If `a.extended_gcd(&b) == (g, x, y)` then:
@@ -172,6 +261,111 @@ impl UBig {
 
         (b << zeros, IBig::from(bx), -IBig::from(by))
     }
+
+    /// Modular multiplicative inverse.
+    ///
+    /// Returns `x` in range `[0, modulus)` such that `self * x` is congruent to 1 modulo
+    /// `modulus`, or `None` if `self` and `modulus` are not coprime.
+    ///
+    /// Every number is its own inverse modulo 1, so modulus 1 gives `Some(0)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(7).mod_inverse(&ubig!(10)), Some(ubig!(3)));
+    /// assert_eq!(ubig!(2).mod_inverse(&ubig!(10)), None);
+    /// assert_eq!(ubig!(5).mod_inverse(&ubig!(1)), Some(ubig!(0)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn mod_inverse(&self, modulus: &UBig) -> Option<UBig> {
+        if *modulus == UBig::from_word(0) {
+            panic!("mod_inverse with modulus 0");
+        }
+        if *modulus == UBig::from_word(1) {
+            return Some(UBig::from_word(0));
+        }
+        let a = self % modulus;
+        if a == UBig::from_word(0) {
+            return None;
+        }
+        let (g, x, _) = a.extended_gcd(modulus);
+        if g != UBig::from_word(1) {
+            return None;
+        }
+        Some(x.rem_euclid(IBig::from(modulus)).unsigned_abs())
+    }
+}
+
+/// Use Lehmer's algorithm for GCD when both numbers have at least this many words.
+const LEHMER_THRESHOLD: usize = 4;
+
+/// The combined effect of several Euclidean steps on the leading words of `a >= b`:
+///
+/// `(a, b) <- (u0 * a - v0 * b, v1 * b - u1 * a)` if `even`,
+/// `(a, b) <- (v0 * b - u0 * a, u1 * a - v1 * b)` otherwise.
+///
+/// The signs alternate with each step, so only magnitudes are stored.
+struct LehmerStep {
+    u0: Word,
+    u1: Word,
+    v0: Word,
+    v1: Word,
+    even: bool,
+}
+
+impl LehmerStep {
+    /// Simulate Euclidean steps on the leading words, stopping by Collins' condition while
+    /// the quotients are still guaranteed to be those of the full numbers.
+    ///
+    /// Requires `a >= b` and `b` at least 2 words long.
+    fn simulate(a: &[Word], b: &[Word]) -> LehmerStep {
+        let (n, m) = (a.len(), b.len());
+        debug_assert!(n >= m && m >= 2);
+        let shift = a[n - 1].leading_zeros();
+        let top = |hi: Word, lo: Word| {
+            if shift == 0 {
+                hi
+            } else {
+                hi << shift | lo >> (WORD_BITS - shift)
+            }
+        };
+        let mut a1 = top(a[n - 1], a[n - 2]);
+        let mut a2 = if n == m {
+            top(b[n - 1], b[n - 2])
+        } else if n == m + 1 {
+            top(0, b[n - 2])
+        } else {
+            0
+        };
+
+        let mut step = LehmerStep {
+            u0: 0,
+            u1: 1,
+            v0: 0,
+            v1: 0,
+            even: false,
+        };
+        let (mut u2, mut v2): (Word, Word) = (0, 1);
+        // Cofactors are bounded by the leading words, so they never overflow.
+        while a2 >= v2 && a1 - a2 >= step.v1 + v2 {
+            let (q, r) = (a1 / a2, a1 % a2);
+            a1 = a2;
+            a2 = r;
+            let next_u = step.u1 + q * u2;
+            step.u0 = step.u1;
+            step.u1 = u2;
+            u2 = next_u;
+            let next_v = step.v1 + q * v2;
+            step.v0 = step.v1;
+            step.v1 = v2;
+            v2 = next_v;
+            step.even = !step.even;
+        }
+        step
+    }
 }
 
 impl IBig {
@@ -191,6 +385,37 @@ impl IBig {
         self.magnitude().gcd(rhs.magnitude()).into()
     }
 
+    /// Least common multiple, always non-negative.
+    ///
+    /// `lcm(0, x)` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-12).lcm(&ibig!(18)), ibig!(36));
+    /// ```
+    pub fn lcm(&self, rhs: &IBig) -> IBig {
+        self.magnitude().lcm(rhs.magnitude()).into()
+    }
+
+    /// Greatest common divisor and least common multiple, both non-negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-12).gcd_lcm(&ibig!(-18)), (ibig!(6), ibig!(36)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `ibig!(0).gcd_lcm(&ibig!(0))` panics.
+    pub fn gcd_lcm(&self, rhs: &IBig) -> (IBig, IBig) {
+        let (g, lcm) = self.magnitude().gcd_lcm(rhs.magnitude());
+        (g.into(), lcm.into())
+    }
+
     /// Greatest common divisors and the Bézout coefficients.
     ///
     /// If `a.extended_gcd(&b) == (g, x, y)` then:
@@ -36,8 +36,19 @@ impl IBig {
         IBig { sign, magnitude }
     }
 
+    /// The sign of the number.
+    ///
+    /// Zero has a positive sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, Sign};
+    /// assert_eq!(ibig!(-3).sign(), Sign::Negative);
+    /// assert_eq!(ibig!(0).sign(), Sign::Positive);
+    /// ```
     #[inline]
-    pub(crate) fn sign(&self) -> Sign {
+    pub fn sign(&self) -> Sign {
         self.sign
     }
 
@@ -50,4 +61,89 @@ impl IBig {
     pub(crate) fn into_sign_magnitude(self) -> (Sign, UBig) {
         (self.sign, self.magnitude)
     }
+
+    /// Construct from a sign and a magnitude.
+    ///
+    /// The sign of zero is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, ubig, IBig, Sign};
+    /// assert_eq!(IBig::from_parts(Sign::Negative, ubig!(5)), ibig!(-5));
+    /// assert_eq!(IBig::from_parts(Sign::Negative, ubig!(0)).sign(), Sign::Positive);
+    /// ```
+    #[inline]
+    pub fn from_parts(sign: Sign, magnitude: UBig) -> IBig {
+        IBig::from_sign_magnitude(sign, magnitude)
+    }
+
+    /// Split into the sign and the magnitude.
+    ///
+    /// Zero gives [Sign::Positive].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, ubig, Sign};
+    /// assert_eq!(ibig!(-5).into_parts(), (Sign::Negative, ubig!(5)));
+    /// ```
+    #[inline]
+    pub fn into_parts(self) -> (Sign, UBig) {
+        self.into_sign_magnitude()
+    }
+
+    /// Zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// assert_eq!(IBig::zero(), ibig!(0));
+    /// ```
+    #[inline]
+    pub fn zero() -> IBig {
+        IBig::from(UBig::zero())
+    }
+
+    /// One.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// assert_eq!(IBig::one(), ibig!(1));
+    /// ```
+    #[inline]
+    pub fn one() -> IBig {
+        IBig::from(UBig::one())
+    }
+
+    /// Check whether the number is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert!(ibig!(0).is_zero());
+    /// assert!(!ibig!(-1).is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Check whether the number is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert!(ibig!(1).is_one());
+    /// assert!(!ibig!(-1).is_one());
+    /// ```
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        self.sign == Positive && self.magnitude.is_one()
+    }
 }
@@ -0,0 +1,47 @@
+//! Low-level access to the representation of numbers.
+//!
+//! This module is intended for crates that implement their own arithmetic on top of the
+//! [Word]s of a [UBig](crate::UBig). It is **unstable**: its contents may change in any release,
+//! including the sizes of the types, which depend on the target platform.
+//!
+//! The words of a number are available through [UBig::as_words](crate::UBig::as_words),
+//! [UBig::from_words](crate::UBig::from_words) and [UBigBuilder](crate::UBigBuilder).
+//!
+//! # Examples
+//!
+//! ```
+//! use ibig::internals::{add_with_carry, Word};
+//! assert_eq!(add_with_carry(Word::MAX, 1, false), (0, true));
+//! assert_eq!(add_with_carry(2, 3, true), (6, false));
+//! ```
+
+use crate::arch;
+
+pub use crate::{
+    arch::word::{SignedWord, Word},
+    WORD_BITS,
+};
+
+/// Add `a + b + carry`.
+///
+/// Returns the low [Word] of the result and the carry out.
+#[inline]
+pub fn add_with_carry(a: Word, b: Word, carry: bool) -> (Word, bool) {
+    arch::add::add_with_carry(a, b, carry)
+}
+
+/// Subtract `a - b - borrow`.
+///
+/// Returns the low [Word] of the result and the borrow out.
+///
+/// # Examples
+///
+/// ```
+/// use ibig::internals::{sub_with_borrow, Word};
+/// assert_eq!(sub_with_borrow(0, 1, false), (Word::MAX, true));
+/// assert_eq!(sub_with_borrow(5, 3, true), (1, false));
+/// ```
+#[inline]
+pub fn sub_with_borrow(a: Word, b: Word, borrow: bool) -> (Word, bool) {
+    arch::add::sub_with_borrow(a, b, borrow)
+}
@@ -54,16 +54,20 @@
 //!
 //! # Optional dependencies
 //!
-//! * `std` (default): for `std::error::Error`.
+//! * `std` (default): for `std::error::Error` and [UBig::from_reader].
 //! * `num-traits` (default): integral traits.
 //! * `rand` (default): random number generation.
 //! * `serde`: serialization and deserialization.
+//! * `rayon`: parallel multiplication of large numbers, and [UBig::par_product_of_slice].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
-pub use crate::{ibig::IBig, ubig::UBig};
+pub use crate::{arch::word::Word, builder::UBigBuilder, ibig::IBig, sign::Sign, ubig::UBig};
+
+/// Number of bits in a [Word].
+pub const WORD_BITS: usize = primitive::WORD_BITS_USIZE;
 
 mod add;
 mod add_ops;
@@ -71,8 +75,11 @@ mod arch;
 mod assert;
 mod bits;
 mod buffer;
+mod builder;
 mod cmp;
+mod combinatorics;
 mod convert;
+mod digits;
 mod div;
 mod div_ops;
 pub mod error;
@@ -81,6 +88,8 @@ pub mod fmt;
 mod gcd;
 mod helper_macros;
 mod ibig;
+pub mod internals;
+mod log;
 mod macros;
 mod math;
 mod memory;
@@ -90,8 +99,10 @@ mod mul_ops;
 pub mod ops;
 mod parse;
 mod pow;
+mod prime;
 mod primitive;
 mod radix;
+mod root;
 mod shift;
 mod shift_ops;
 mod sign;
@@ -0,0 +1,141 @@
+//! Integer logarithms.
+
+use crate::{
+    arch::word::Word,
+    ubig::{Repr::*, UBig},
+};
+use alloc::vec::Vec;
+
+/// log10(2) in 64-bit fixed point, rounded down.
+const LOG10_2_FIXED: u128 = 5553023288523357132;
+
+impl UBig {
+    /// Base 2 logarithm, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(1).ilog2(), 0);
+    /// assert_eq!(ubig!(1023).ilog2(), 9);
+    /// assert_eq!(ubig!(1024).ilog2(), 10);
+    /// ```
+    #[inline]
+    pub fn ilog2(&self) -> usize {
+        match self.bit_len() {
+            0 => panic_log_of_zero(),
+            n => n - 1,
+        }
+    }
+
+    /// Base 10 logarithm, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(999).ilog10(), 2);
+    /// assert_eq!(ubig!(1000).ilog10(), 3);
+    /// ```
+    #[inline]
+    pub fn ilog10(&self) -> usize {
+        match self.repr() {
+            Small(0) => panic_log_of_zero(),
+            Small(word) => ilog_word(*word, 10),
+            Large(_) => self.ilog10_large(),
+        }
+    }
+
+    /// Logarithm in base `base`, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is 0 or `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(80).ilog(&ubig!(3)), 3);
+    /// assert_eq!(ubig!(81).ilog(&ubig!(3)), 4);
+    /// ```
+    pub fn ilog(&self, base: &UBig) -> usize {
+        if *self == 0u8 {
+            panic_log_of_zero();
+        }
+        if *base < 2u8 {
+            panic!("base of integer logarithm must be at least 2");
+        }
+        match (self.repr(), base.repr()) {
+            (Small(word), Small(base)) => ilog_word(*word, *base),
+            (Small(_), Large(_)) => 0,
+            _ => self.ilog_large(base),
+        }
+    }
+
+    /// Base 10 logarithm estimated from the bit length, then corrected with a power of ten.
+    fn ilog10_large(&self) -> usize {
+        // 2^(n-1) <= self < 2^n, so the result is floor((n-1) log10(2)) or one more.
+        // LOG10_2_FIXED is just below log10(2), which can make the estimate one too low.
+        let n = self.bit_len();
+        let mut exp = (((n - 1) as u128 * LOG10_2_FIXED) >> 64) as usize;
+        let mut power = UBig::from_word(10).pow(exp + 1);
+        while power <= *self {
+            exp += 1;
+            power *= UBig::from_word(10);
+        }
+        exp
+    }
+
+    /// Logarithm by binary search over the exponent, using repeated squarings of `base`.
+    fn ilog_large(&self, base: &UBig) -> usize {
+        // powers[i] = base^(2^i) <= self
+        let mut powers: Vec<UBig> = Vec::new();
+        let mut power = base.clone();
+        while power <= *self {
+            let square = if 2 * power.bit_len() - 1 <= self.bit_len() {
+                Some(&power * &power)
+            } else {
+                None
+            };
+            powers.push(power);
+            match square {
+                Some(square) => power = square,
+                None => break,
+            }
+        }
+
+        let mut exp = 0;
+        let mut acc = UBig::from_word(1);
+        for (i, power) in powers.iter().enumerate().rev() {
+            let next = &acc * power;
+            if next <= *self {
+                acc = next;
+                exp += 1 << i;
+            }
+        }
+        exp
+    }
+}
+
+/// Logarithm of a word in base `base` >= 2, rounded down.
+fn ilog_word(mut word: Word, base: Word) -> usize {
+    let mut exp = 0;
+    while word >= base {
+        word /= base;
+        exp += 1;
+    }
+    exp
+}
+
+fn panic_log_of_zero() -> ! {
+    panic!("argument of integer logarithm must be positive")
+}
@@ -31,20 +31,32 @@
 /// assert_eq!(g, h);
 /// let i = ubig!(_100ef base 32);
 /// ```
+///
+/// Digits may be separated by single underscores, as in Rust integer literals:
+/// ```
+/// # use ibig::ubig;
+/// let j = ubig!(1_000_000);
+/// let k = ubig!(_1_000_000_000_000_000_000_000_000_000_000_000_000_000);
+/// let l = ubig!(_0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff);
+/// let m = ubig!(zz_zz base 36);
+/// ```
 #[macro_export]
 macro_rules! ubig {
     ($val:ident) => {{
         let s = ::core::stringify!($val);
         let s = ::core::option::Option::unwrap_or(::core::primitive::str::strip_prefix(s, "_"), s);
         ::core::result::Result::expect(
-            $crate::UBig::from_str_with_radix_prefix(s),
+            $crate::UBig::from_str_with_radix_prefix_separated(s),
             "invalid number",
         )
     }};
     ($val:ident base $radix:literal) => {{
         let s = ::core::stringify!($val);
         let s = ::core::option::Option::unwrap_or(::core::primitive::str::strip_prefix(s, "_"), s);
-        ::core::result::Result::expect($crate::UBig::from_str_radix(s, $radix), "invalid number")
+        ::core::result::Result::expect(
+            $crate::UBig::from_str_radix_separated(s, $radix),
+            "invalid number",
+        )
     }};
     ($val:literal) => {{
         let val: ::core::primitive::u128 = $val;
@@ -53,7 +65,10 @@ macro_rules! ubig {
     ($val:literal base $radix:literal) => {{
         let s = ::core::stringify!($val);
         let s = ::core::option::Option::unwrap_or(::core::primitive::str::strip_prefix(s, "_"), s);
-        ::core::result::Result::expect($crate::UBig::from_str_radix(s, $radix), "invalid number")
+        ::core::result::Result::expect(
+            $crate::UBig::from_str_radix_separated(s, $radix),
+            "invalid number",
+        )
     }};
 }
 
@@ -88,6 +103,13 @@ macro_rules! ubig {
 /// assert_eq!(g, h);
 /// let i = ibig!(-_100ef base 32);
 /// ```
+///
+/// Digits may be separated by single underscores, as in Rust integer literals:
+/// ```
+/// # use ibig::ibig;
+/// let j = ibig!(-1_000_000);
+/// let k = ibig!(-_0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff);
+/// ```
 #[macro_export]
 macro_rules! ibig {
     (- $val:ident) => {
@@ -87,6 +87,19 @@ pub(crate) const fn ones_word(n: u32) -> Word {
     }
 }
 
+/// Multiplicative inverse of an odd word modulo 2^WORD_BITS.
+pub(crate) fn inverse_word(n: Word) -> Word {
+    debug_assert!(n & 1 == 1);
+    // n * n = 1 (mod 8), and each Newton step doubles the number of correct bits.
+    let mut inv = n;
+    let mut correct_bits = 3;
+    while correct_bits < Word::BIT_SIZE {
+        inv = inv.wrapping_mul((2 as Word).wrapping_sub(n.wrapping_mul(inv)));
+        correct_bits *= 2;
+    }
+    inv
+}
+
 #[inline]
 pub(crate) const fn min_usize(a: usize, b: usize) -> usize {
     if a < b {
@@ -41,7 +41,7 @@ impl MemoryAllocation {
 
     /// Get memory.
     #[inline]
-    pub(crate) fn memory(&mut self) -> Memory {
+    pub(crate) fn memory(&mut self) -> Memory<'_> {
         Memory {
             start: self.start,
             end: self.start.wrapping_add(self.layout.size()),
@@ -59,7 +59,37 @@ impl Drop for MemoryAllocation {
     }
 }
 
+// Memory is an exclusive borrow of a chunk of bytes, like `&'a mut [u8]`.
+#[cfg(feature = "rayon")]
+unsafe impl Send for Memory<'_> {}
+
 impl Memory<'_> {
+    /// Split off a chunk of memory large enough for `layout`.
+    ///
+    /// Returns the chunk and the remaining memory. The two are disjoint, so they can be used
+    /// independently, e.g. on different threads.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split(&mut self, layout: Layout) -> (Memory<'_>, Memory<'_>) {
+        let start = self.start as usize;
+        let padding = start.wrapping_neg() & (layout.align() - 1);
+        let mid = start
+            .checked_add(padding)
+            .and_then(|x| x.checked_add(layout.size()))
+            .filter(|&mid| mid <= self.end as usize)
+            .unwrap_or_else(|| panic_allocated_too_little()) as *mut u8;
+        let first = Memory {
+            start: self.start,
+            end: mid,
+            phantom_data: PhantomData,
+        };
+        let second = Memory {
+            start: mid,
+            end: self.end,
+            phantom_data: PhantomData,
+        };
+        (first, second)
+    }
+
     /// Allocate a slice with a given value.
     ///
     /// Returns the remaining chunk of memory.
@@ -67,7 +97,11 @@ impl Memory<'_> {
     /// The original memory is not usable until both the new memory and the slice are dropped.
     ///
     /// The elements of the slice never get dropped!
-    pub(crate) fn allocate_slice_fill<T: Copy>(&mut self, n: usize, val: T) -> (&mut [T], Memory) {
+    pub(crate) fn allocate_slice_fill<T: Copy>(
+        &mut self,
+        n: usize,
+        val: T,
+    ) -> (&mut [T], Memory<'_>) {
         self.allocate_slice_initialize::<T, _>(n, |ptr| {
             for i in 0..n {
                 // Safe because ptr is properly aligned and has enough space.
@@ -85,7 +119,7 @@ impl Memory<'_> {
     /// The original memory is not usable until both the new memory and the slice are dropped.
     ///
     /// The elements of the slice never get dropped!
-    pub(crate) fn allocate_slice_copy<T: Copy>(&mut self, source: &[T]) -> (&mut [T], Memory) {
+    pub(crate) fn allocate_slice_copy<T: Copy>(&mut self, source: &[T]) -> (&mut [T], Memory<'_>) {
         self.allocate_slice_initialize::<T, _>(source.len(), |ptr| {
             for (i, v) in source.iter().enumerate() {
                 // Safe because ptr is properly aligned and has enough space.
@@ -108,7 +142,7 @@ impl Memory<'_> {
         n: usize,
         source: &[T],
         val: T,
-    ) -> (&mut [T], Memory) {
+    ) -> (&mut [T], Memory<'_>) {
         assert!(n >= source.len());
 
         self.allocate_slice_initialize::<T, _>(n, |ptr| {
@@ -127,7 +161,7 @@ impl Memory<'_> {
         })
     }
 
-    fn allocate_slice_initialize<T, F>(&mut self, n: usize, init: F) -> (&mut [T], Memory)
+    fn allocate_slice_initialize<T, F>(&mut self, n: usize, init: F) -> (&mut [T], Memory<'_>)
     where
         F: FnOnce(*mut T),
     {
@@ -225,6 +259,27 @@ mod tests {
         let _ = new_memory.allocate_slice_fill::<u32>(2, 4);
     }
 
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_memory_split() {
+        let mut scratchpad = MemoryAllocation::new(Layout::from_size_align(16, 4).unwrap());
+        let mut memory = scratchpad.memory();
+        let (mut first, mut second) = memory.split(Layout::from_size_align(8, 4).unwrap());
+        let (a, _) = first.allocate_slice_fill::<u32>(2, 3);
+        let (b, _) = second.allocate_slice_fill::<u32>(2, 4);
+        assert_eq!(a, &[3, 3]);
+        assert_eq!(b, &[4, 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    #[should_panic]
+    fn test_memory_split_too_large() {
+        let mut scratchpad = MemoryAllocation::new(Layout::from_size_align(8, 4).unwrap());
+        let mut memory = scratchpad.memory();
+        let _ = memory.split(Layout::from_size_align(12, 4).unwrap());
+    }
+
     #[test]
     fn test_add_layout() {
         let layout = add_layout(
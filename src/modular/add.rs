@@ -6,6 +6,7 @@ use crate::{
         modulo::{Modulo, ModuloLarge, ModuloRepr, ModuloSmall, ModuloSmallRaw},
         modulo_ring::ModuloRingSmall,
     },
+    ubig::UBig,
 };
 use core::{
     cmp::Ordering,
@@ -161,6 +162,51 @@ impl<'a> SubAssign<&Modulo<'a>> for Modulo<'a> {
     }
 }
 
+impl UBig {
+    /// Modular addition: `(self + rhs) mod modulus`.
+    ///
+    /// `self` and `rhs` must already be reduced, i.e. less than `modulus`. The sum is then
+    /// reduced by a single subtraction. For repeated operations with the same modulus, use
+    /// [ModuloRing](crate::modular::ModuloRing).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(7).mod_add(&ubig!(5), &ubig!(10)), ubig!(2));
+    /// assert_eq!(ubig!(3).mod_add(&ubig!(5), &ubig!(10)), ubig!(8));
+    /// ```
+    #[inline]
+    pub fn mod_add(&self, rhs: &UBig, modulus: &UBig) -> UBig {
+        debug_assert!(self < modulus && rhs < modulus);
+        let mut sum = self + rhs;
+        sum.reduce_once(modulus);
+        sum
+    }
+
+    /// Modular subtraction: `(self - rhs) mod modulus`.
+    ///
+    /// `self` and `rhs` must already be reduced, i.e. less than `modulus`. If `rhs > self`, the
+    /// difference wraps around to `self + modulus - rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(7).mod_sub(&ubig!(5), &ubig!(10)), ubig!(2));
+    /// assert_eq!(ubig!(3).mod_sub(&ubig!(5), &ubig!(10)), ubig!(8));
+    /// ```
+    #[inline]
+    pub fn mod_sub(&self, rhs: &UBig, modulus: &UBig) -> UBig {
+        debug_assert!(self < modulus && rhs < modulus);
+        if self >= rhs {
+            self - rhs
+        } else {
+            modulus - rhs + self
+        }
+    }
+}
+
 impl ModuloSmallRaw {
     /// -self
     #[inline]
@@ -0,0 +1,87 @@
+//! Barrett reduction.
+
+use crate::{primitive::WORD_BITS_USIZE, ubig::UBig};
+
+/// Precomputed values for Barrett reduction modulo a fixed number `n`.
+///
+/// Reduction of `x < n^2` takes two multiplications and a subtraction instead of a division.
+/// Unlike [MontgomeryCtx](crate::modular::MontgomeryCtx), any nonzero modulus is supported,
+/// including even ones.
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{modular::BarrettCtx, ubig};
+/// let ctx = BarrettCtx::new(&ubig!(1000));
+/// assert_eq!(ctx.reduce(&ubig!(123456)), ubig!(456));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BarrettCtx {
+    modulus: UBig,
+    /// Number of words in the modulus.
+    k: usize,
+    /// floor(B^(2k) / n), where B = 2^WORD_BITS.
+    mu: UBig,
+}
+
+impl BarrettCtx {
+    /// Create a Barrett context for `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{modular::BarrettCtx, ubig};
+    /// let ctx = BarrettCtx::new(&ubig!(100));
+    /// assert_eq!(*ctx.modulus(), ubig!(100));
+    /// ```
+    pub fn new(modulus: &UBig) -> BarrettCtx {
+        if *modulus == 0u8 {
+            panic!("BarrettCtx::new(0)");
+        }
+        let k = modulus.as_words().len();
+        let mu = (UBig::from_word(1) << (2 * k * WORD_BITS_USIZE)) / modulus;
+        BarrettCtx {
+            modulus: modulus.clone(),
+            k,
+            mu,
+        }
+    }
+
+    /// The modulus.
+    #[inline]
+    pub fn modulus(&self) -> &UBig {
+        &self.modulus
+    }
+
+    /// `x mod n`.
+    ///
+    /// Values up to `n^2` (more precisely, below `B^(2k)` where `k` is the number of words in
+    /// `n`) are reduced without division. Larger values fall back to ordinary division.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{modular::BarrettCtx, ubig};
+    /// let n = (ubig!(1) << 200) + ubig!(2);
+    /// let ctx = BarrettCtx::new(&n);
+    /// let x = (ubig!(1) << 390) + ubig!(12345);
+    /// assert_eq!(ctx.reduce(&x), &x % &n);
+    /// ```
+    pub fn reduce(&self, x: &UBig) -> UBig {
+        if x.as_words().len() > 2 * self.k {
+            return x % &self.modulus;
+        }
+        // q = floor(floor(x / B^(k-1)) * mu / B^(k+1)) is at most 2 less than floor(x / n).
+        let q = ((x >> ((self.k - 1) * WORD_BITS_USIZE)) * &self.mu)
+            >> ((self.k + 1) * WORD_BITS_USIZE);
+        let mut r = x - q * &self.modulus;
+        while r >= self.modulus {
+            r -= &self.modulus;
+        }
+        r
+    }
+}
@@ -48,7 +48,7 @@ impl ModuloRing {
     /// assert!(x == y);
     /// ```
     #[inline]
-    pub fn from<T: IntoModulo>(&self, x: T) -> Modulo {
+    pub fn from<T: IntoModulo>(&self, x: T) -> Modulo<'_> {
         x.into_modulo(self)
     }
 }
@@ -64,7 +64,7 @@ impl ModuloRingLarge {
     pub(crate) fn modulus(&self) -> UBig {
         let normalized_modulus = self.normalized_modulus();
         let mut buffer = Buffer::allocate(normalized_modulus.len());
-        buffer.extend(normalized_modulus);
+        buffer.extend_from_slice(normalized_modulus);
         let low_bits = shift::shr_in_place(&mut buffer, self.shift());
         assert!(low_bits == 0);
         buffer.into()
@@ -128,7 +128,7 @@ impl ModuloLarge<'_> {
     pub(crate) fn residue(&self) -> UBig {
         let words = self.normalized_value();
         let mut buffer = Buffer::allocate(words.len());
-        buffer.extend(words);
+        buffer.extend_from_slice(words);
         let low_bits = shift::shr_in_place(&mut buffer, self.ring().shift());
         assert!(low_bits == 0);
         buffer.into()
@@ -137,12 +137,12 @@ impl ModuloLarge<'_> {
 
 /// Trait for types that can be converted into [Modulo] in a [ModuloRing].
 pub trait IntoModulo {
-    fn into_modulo(self, ring: &ModuloRing) -> Modulo;
+    fn into_modulo(self, ring: &ModuloRing) -> Modulo<'_>;
 }
 
 impl IntoModulo for UBig {
     #[inline]
-    fn into_modulo(self, ring: &ModuloRing) -> Modulo {
+    fn into_modulo(self, ring: &ModuloRing) -> Modulo<'_> {
         match ring.repr() {
             ModuloRingRepr::Small(ring_small) => ModuloSmall::from_ubig(&self, ring_small).into(),
             ModuloRingRepr::Large(ring_large) => ModuloLarge::from_ubig(self, ring_large).into(),
@@ -152,7 +152,7 @@ impl IntoModulo for UBig {
 
 impl IntoModulo for &UBig {
     #[inline]
-    fn into_modulo(self, ring: &ModuloRing) -> Modulo {
+    fn into_modulo(self, ring: &ModuloRing) -> Modulo<'_> {
         match ring.repr() {
             ModuloRingRepr::Small(ring_small) => ModuloSmall::from_ubig(self, ring_small).into(),
             ModuloRingRepr::Large(ring_large) => {
@@ -164,7 +164,7 @@ impl IntoModulo for &UBig {
 
 impl IntoModulo for IBig {
     #[inline]
-    fn into_modulo(self, ring: &ModuloRing) -> Modulo {
+    fn into_modulo(self, ring: &ModuloRing) -> Modulo<'_> {
         let (sign, mag) = self.into_sign_magnitude();
         let modulo = mag.into_modulo(ring);
         match sign {
@@ -176,7 +176,7 @@ impl IntoModulo for IBig {
 
 impl IntoModulo for &IBig {
     #[inline]
-    fn into_modulo(self, ring: &ModuloRing) -> Modulo {
+    fn into_modulo(self, ring: &ModuloRing) -> Modulo<'_> {
         let modulo = self.magnitude().into_modulo(ring);
         match self.sign() {
             Positive => modulo,
@@ -15,16 +15,20 @@
 //! assert_eq!(format!("{}", x - y), "6902 (mod 10000)");
 //! ```
 
+pub use barrett::BarrettCtx;
 pub use convert::IntoModulo;
 pub use modulo::Modulo;
 pub use modulo_ring::ModuloRing;
+pub use montgomery::MontgomeryCtx;
 
 mod add;
+mod barrett;
 mod cmp;
 pub(crate) mod convert;
 mod div;
 mod fmt;
 pub(crate) mod modulo;
 pub(crate) mod modulo_ring;
+mod montgomery;
 mod mul;
 mod pow;
@@ -0,0 +1,201 @@
+//! Montgomery multiplication.
+
+use crate::{
+    arch::word::Word, buffer::Buffer, math, modular::modulo::ModuloLarge, mul,
+    primitive::WORD_BITS_USIZE, ubig::UBig,
+};
+use alloc::vec::Vec;
+
+/// Precomputed values for Montgomery multiplication modulo an odd number `n`.
+///
+/// Numbers are represented in Montgomery form `x * R mod n`, where
+/// `R = 2^(WORD_BITS * k)` and `k` is the number of words in `n`. Multiplication in this form
+/// replaces division by `n` with a cheaper reduction (REDC), which pays off when many
+/// multiplications are done with the same modulus, as in exponentiation.
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{modular::MontgomeryCtx, ubig};
+/// let ctx = MontgomeryCtx::new(&ubig!(497)).unwrap();
+/// assert_eq!(ctx.pow_mod(&ubig!(4), &ubig!(13)), ubig!(445));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MontgomeryCtx {
+    modulus: UBig,
+    /// -n^-1 mod 2^WORD_BITS.
+    neg_inv: Word,
+    /// R mod n, the Montgomery form of 1.
+    r: UBig,
+    /// R^2 mod n.
+    r2: UBig,
+}
+
+impl MontgomeryCtx {
+    /// Create a Montgomery context for `modulus`.
+    ///
+    /// Returns `None` if `modulus` is even, including 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{modular::MontgomeryCtx, ubig};
+    /// assert!(MontgomeryCtx::new(&ubig!(101)).is_some());
+    /// assert!(MontgomeryCtx::new(&ubig!(100)).is_none());
+    /// ```
+    pub fn new(modulus: &UBig) -> Option<MontgomeryCtx> {
+        if !modulus.bit(0) {
+            return None;
+        }
+        let r_bits = modulus.as_words().len() * WORD_BITS_USIZE;
+        let r = (UBig::from_word(1) << r_bits) % modulus;
+        let r2 = (&r * &r) % modulus;
+        Some(MontgomeryCtx {
+            modulus: modulus.clone(),
+            neg_inv: math::inverse_word(modulus.as_words()[0]).wrapping_neg(),
+            r,
+            r2,
+        })
+    }
+
+    /// The modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{modular::MontgomeryCtx, ubig};
+    /// let ctx = MontgomeryCtx::new(&ubig!(101)).unwrap();
+    /// assert_eq!(*ctx.modulus(), ubig!(101));
+    /// ```
+    #[inline]
+    pub fn modulus(&self) -> &UBig {
+        &self.modulus
+    }
+
+    /// Convert `x` into Montgomery form: `x * R mod n`.
+    ///
+    /// `x` does not need to be reduced.
+    #[inline]
+    pub fn to_montgomery(&self, x: &UBig) -> UBig {
+        self.mont_mul(&(x % &self.modulus), &self.r2)
+    }
+
+    /// Convert `x` out of Montgomery form: `x * R^-1 mod n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is not less than the modulus.
+    #[inline]
+    pub fn from_montgomery(&self, x: &UBig) -> UBig {
+        assert!(*x < self.modulus, "value is not reduced");
+        self.redc(x)
+    }
+
+    /// Montgomery product: `a * b * R^-1 mod n`.
+    ///
+    /// If `a` and `b` are in Montgomery form, so is the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not less than the modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{modular::MontgomeryCtx, ubig};
+    /// let ctx = MontgomeryCtx::new(&ubig!(101)).unwrap();
+    /// let a = ctx.to_montgomery(&ubig!(12));
+    /// let b = ctx.to_montgomery(&ubig!(34));
+    /// assert_eq!(ctx.from_montgomery(&ctx.mont_mul(&a, &b)), ubig!(408) % ubig!(101));
+    /// ```
+    #[inline]
+    pub fn mont_mul(&self, a: &UBig, b: &UBig) -> UBig {
+        assert!(
+            *a < self.modulus && *b < self.modulus,
+            "value is not reduced"
+        );
+        self.redc(&(a * b))
+    }
+
+    /// Modular exponentiation: `base^exp mod n`.
+    ///
+    /// `base` is converted into Montgomery form once, all squarings and multiplications are
+    /// done with Montgomery reduction, and the result is converted back.
+    ///
+    /// Large exponents are processed with a sliding window over a table of odd powers of
+    /// `base`. Small exponents use plain square-and-multiply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{modular::MontgomeryCtx, ubig};
+    /// let p = ubig!(2).pow(607) - ubig!(1);
+    /// let ctx = MontgomeryCtx::new(&p).unwrap();
+    /// assert_eq!(ctx.pow_mod(&ubig!(3), &(&p - ubig!(1))), ubig!(1));
+    /// ```
+    pub fn pow_mod(&self, base: &UBig, exp: &UBig) -> UBig {
+        let base = self.to_montgomery(base);
+        let window_len = ModuloLarge::choose_pow_window_len(exp.bit_len()) as usize;
+
+        // table[i] = base^(2i+1)
+        let mut table = Vec::with_capacity(1 << (window_len - 1));
+        table.push(base);
+        if window_len > 1 {
+            let square = self.redc(&(&table[0] * &table[0]));
+            for i in 1..1 << (window_len - 1) {
+                let next = self.redc(&(&table[i - 1] * &square));
+                table.push(next);
+            }
+        }
+
+        let mut res = self.r.clone();
+        // res = base^exp[bit..]
+        let mut bit = exp.bit_len();
+        while bit > 0 {
+            if !exp.bit(bit - 1) {
+                res = self.redc(&(&res * &res));
+                bit -= 1;
+                continue;
+            }
+            // The longest window exp[low..bit] of at most window_len bits with the lowest bit
+            // set.
+            let mut low = bit.saturating_sub(window_len);
+            while !exp.bit(low) {
+                low += 1;
+            }
+            let mut window = 0;
+            for i in (low..bit).rev() {
+                res = self.redc(&(&res * &res));
+                window = 2 * window + usize::from(exp.bit(i));
+            }
+            res = self.redc(&(&res * &table[window >> 1]));
+            bit = low;
+        }
+        self.redc(&res)
+    }
+
+    /// Montgomery reduction: `t * R^-1 mod n` for `t < n * R`.
+    fn redc(&self, t: &UBig) -> UBig {
+        let n = self.modulus.as_words();
+        let k = n.len();
+        // t + sum(m_i * n * 2^(WORD_BITS * i)) < 2 * n * R fits in 2k + 1 words.
+        let len = 2 * k + 1;
+        let t = t.as_words();
+        debug_assert!(t.len() <= 2 * k);
+        let mut buffer = Buffer::allocate(len);
+        buffer.extend_from_slice(t);
+        buffer.push_zeros(len - t.len());
+        for i in 0..k {
+            // Make word i zero.
+            let m = buffer[i].wrapping_mul(self.neg_inv);
+            let carry = mul::add_mul_word_in_place(&mut buffer[i..], m, n);
+            debug_assert!(carry == 0);
+        }
+        buffer.erase_front(k);
+        let mut res: UBig = buffer.into();
+        if res >= self.modulus {
+            res -= &self.modulus;
+        }
+        res
+    }
+}
@@ -11,6 +11,7 @@ use crate::{
     primitive::extend_word,
     shift,
     sign::Sign::Positive,
+    ubig::UBig,
 };
 use alloc::alloc::Layout;
 use core::ops::{Mul, MulAssign};
@@ -79,6 +80,29 @@ impl<'a> MulAssign<&Modulo<'a>> for Modulo<'a> {
     }
 }
 
+impl UBig {
+    /// Modular multiplication: `(self * rhs) mod modulus`.
+    ///
+    /// `self` and `rhs` are expected to be less than `modulus`, but the result is reduced with a
+    /// full division, so it is correct for any inputs. For repeated operations with the same
+    /// modulus, use [ModuloRing](crate::modular::ModuloRing).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(7).mod_mul(&ubig!(5), &ubig!(10)), ubig!(5));
+    /// ```
+    #[inline]
+    pub fn mod_mul(&self, rhs: &UBig, modulus: &UBig) -> UBig {
+        self * rhs % modulus
+    }
+}
+
 impl ModuloSmallRaw {
     #[inline]
     pub(crate) const fn mul(self, other: ModuloSmallRaw, ring: &ModuloRingSmall) -> ModuloSmallRaw {
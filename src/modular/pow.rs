@@ -1,11 +1,12 @@
 use crate::{
     arch::word::Word,
+    error::ModError,
     ibig::IBig,
     math,
     memory::{self, MemoryAllocation},
     modular::{
         modulo::{Modulo, ModuloLarge, ModuloRepr, ModuloSmall, ModuloSmallRaw},
-        modulo_ring::ModuloRingSmall,
+        modulo_ring::{ModuloRing, ModuloRingSmall},
     },
     primitive::{double_word, split_double_word, PrimitiveUnsigned, WORD_BITS, WORD_BITS_USIZE},
     sign::Sign::*,
@@ -60,6 +61,51 @@ impl<'a> Modulo<'a> {
     }
 }
 
+impl UBig {
+    /// Modular exponentiation: `self^exp mod modulus`.
+    ///
+    /// For repeated operations with the same modulus, use [ModuloRing] directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(4).pow_mod(&ubig!(13), &ubig!(497)), ubig!(445));
+    /// assert_eq!(ubig!(4).pow_mod(&ubig!(13), &ubig!(1)), ubig!(0));
+    /// ```
+    #[inline]
+    pub fn pow_mod(&self, exp: &UBig, modulus: &UBig) -> UBig {
+        ModuloRing::new(modulus).from(self).pow(exp).residue()
+    }
+
+    /// Modular exponentiation: `self^exp mod modulus`, without panicking.
+    ///
+    /// Like [UBig::pow_mod], but returns an error instead of panicking on invalid input.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ModError::ZeroModulus] if `modulus` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{error::ModError, ubig};
+    /// assert_eq!(ubig!(4).checked_pow_mod(&ubig!(13), &ubig!(497)), Ok(ubig!(445)));
+    /// assert_eq!(ubig!(4).checked_pow_mod(&ubig!(13), &ubig!(0)), Err(ModError::ZeroModulus));
+    /// ```
+    #[inline]
+    pub fn checked_pow_mod(&self, exp: &UBig, modulus: &UBig) -> Result<UBig, ModError> {
+        if *modulus == 0u8 {
+            return Err(ModError::ZeroModulus);
+        }
+        Ok(self.pow_mod(exp, modulus))
+    }
+}
+
 impl ModuloSmallRaw {
     /// self^exp
     #[inline]
@@ -165,7 +211,7 @@ impl<'a> ModuloLarge<'a> {
             let (prev, cur) = if i == 1 {
                 (self.normalized_value(), &mut table[0..n])
             } else {
-                let (prev, cur) = (&mut table[(i - 2) * n..i * n]).split_at_mut(n);
+                let (prev, cur) = table[(i - 2) * n..i * n].split_at_mut(n);
                 (&*prev, cur)
             };
             cur.copy_from_slice(self.ring().mul_normalized(
@@ -227,7 +273,7 @@ impl<'a> ModuloLarge<'a> {
 
     /// Choose the optimal window size for n-bit exponents.
     /// 1 <= window_size < min(WORD_BITS, usize::BIT_SIZE) inclusive.
-    fn choose_pow_window_len(n: usize) -> u32 {
+    pub(crate) fn choose_pow_window_len(n: usize) -> u32 {
         // This won't overflow because cost(3) is already approximately usize::MAX / 4
         // and it can only grow by a factor of 2.
         let cost = |window_size| (1usize << (window_size - 1)) - 1 + n / (window_size as usize + 1);
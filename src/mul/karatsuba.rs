@@ -17,6 +17,13 @@ use alloc::alloc::Layout;
 /// Minimum supported length of the factors.
 pub(crate) const MIN_LEN: usize = 3;
 
+/// Minimum length of the factors for which the low and high half-size multiplications are run
+/// in parallel, if the thread pool has more than one thread.
+///
+/// Below this, a half-size multiplication is too short to pay for handing it to another thread.
+#[cfg(feature = "rayon")]
+pub(crate) const MIN_LEN_PARALLEL: usize = 128;
+
 /// Temporary memory required for multiplication.
 ///
 /// n bounds the length of the smaller factor in words.
@@ -31,6 +38,20 @@ pub(crate) fn memory_requirement_up_to(n: usize) -> Layout {
     //       = 2n + 2log (n-1) - Const
     //
     // Use 2n + 2 ceil log_2 n.
+    //
+    // If the two half-size products are computed in parallel, each needs its own memory and
+    // result, and the halves themselves are computed serially:
+    // f(n) = 2n + 2 f(ceil(n/2))
+    //     <= 2n + 2(n+1) + 4(ceil log_2 n - 1)
+    //     <= 4n + 4 ceil log_2 n
+    #[cfg(feature = "rayon")]
+    {
+        if n >= MIN_LEN_PARALLEL {
+            let num_words = 4 * n + 4 * (math::ceil_log_2(n) as usize);
+            return memory::array_layout::<Word>(num_words);
+        }
+    }
+
     let num_words = 2 * n + 2 * (math::ceil_log_2(n) as usize);
     memory::array_layout::<Word>(num_words)
 }
@@ -76,6 +97,13 @@ pub(crate) fn add_signed_mul_same_len(
     debug_assert!(b.len() == n && c.len() == 2 * n);
     debug_assert!(n >= MIN_LEN);
 
+    #[cfg(feature = "rayon")]
+    {
+        if n >= MIN_LEN_PARALLEL && rayon::current_num_threads() > 1 {
+            return add_signed_mul_same_len_parallel(c, sign, a, b, memory);
+        }
+    }
+
     let mid = (n + 1) / 2;
 
     let (a_lo, a_hi) = a.split_at(mid);
@@ -127,3 +155,76 @@ pub(crate) fn add_signed_mul_same_len(
     assert!(carry.abs() <= 1);
     carry
 }
+
+/// c += sign * a * b
+/// Karatsuba method with a_lo * b_lo and a_hi * b_hi computed in parallel.
+///
+/// Returns carry.
+#[cfg(feature = "rayon")]
+#[must_use]
+fn add_signed_mul_same_len_parallel(
+    c: &mut [Word],
+    sign: Sign,
+    a: &[Word],
+    b: &[Word],
+    memory: &mut Memory,
+) -> SignedWord {
+    let n = a.len();
+    debug_assert!(b.len() == n && c.len() == 2 * n);
+
+    let mid = (n + 1) / 2;
+
+    let (a_lo, a_hi) = a.split_at(mid);
+    let (b_lo, b_hi) = b.split_at(mid);
+    let mut carry: SignedWord = 0;
+    let mut carry_c0: SignedWord = 0; // 2*mid
+    let mut carry_c1: SignedWord = 0; // 3*mid
+
+    {
+        // c_0 += a_lo * b_lo
+        // c_1 += a_lo * b_lo + a_hi * b_hi
+        // c_2 += a_hi * b_hi
+        let (c_lo, mut memory) = memory.allocate_slice_fill::<Word>(2 * mid, 0);
+        let (c_hi, mut memory) = memory.allocate_slice_fill::<Word>(2 * (n - mid), 0);
+        let (mut memory_lo, mut memory_hi) =
+            memory.split(mul::memory_requirement_exact(2 * mid, mid));
+        rayon::join(
+            || {
+                let overflow =
+                    mul::add_signed_mul_same_len(c_lo, Positive, a_lo, b_lo, &mut memory_lo);
+                assert!(overflow == 0);
+            },
+            || {
+                let overflow =
+                    mul::add_signed_mul_same_len(c_hi, Positive, a_hi, b_hi, &mut memory_hi);
+                assert!(overflow == 0);
+            },
+        );
+        carry_c0 += add::add_signed_same_len_in_place(&mut c[..2 * mid], sign, c_lo);
+        carry_c1 += add::add_signed_same_len_in_place(&mut c[mid..3 * mid], sign, c_lo);
+        carry += add::add_signed_same_len_in_place(&mut c[2 * mid..], sign, c_hi);
+        carry_c1 += add::add_signed_in_place(&mut c[mid..3 * mid], sign, c_hi);
+    }
+    {
+        // c1 -= (a_lo - a_hi) * (b_lo - b_hi)
+        let (a_diff, mut memory) = memory.allocate_slice_copy(a_lo);
+        let mut diff_sign = add::sub_in_place_with_sign(a_diff, a_hi);
+        let (b_diff, mut memory) = memory.allocate_slice_copy(b_lo);
+        diff_sign *= add::sub_in_place_with_sign(b_diff, b_hi);
+
+        carry_c1 += mul::add_signed_mul_same_len(
+            &mut c[mid..3 * mid],
+            -sign * diff_sign,
+            a_diff,
+            b_diff,
+            &mut memory,
+        );
+    }
+
+    // Propagate carries.
+    carry_c1 += add::add_signed_word_in_place(&mut c[2 * mid..3 * mid], carry_c0);
+    carry += add::add_signed_word_in_place(&mut c[3 * mid..], carry_c1);
+
+    assert!(carry.abs() <= 1);
+    carry
+}
@@ -19,6 +19,9 @@ const_assert!(MAX_LEN_SIMPLE + 1 >= karatsuba::MIN_LEN);
 /// If smaller length <= this, Karatsuba multiplication can be used.
 const MAX_LEN_KARATSUBA: usize = 192;
 const_assert!(MAX_LEN_KARATSUBA + 1 >= toom_3::MIN_LEN);
+// Only the top level of Karatsuba recursion runs in parallel.
+#[cfg(feature = "rayon")]
+const_assert!((MAX_LEN_KARATSUBA + 1) / 2 < karatsuba::MIN_LEN_PARALLEL);
 
 mod helpers;
 mod karatsuba;
@@ -71,7 +74,7 @@ fn add_mul_word_same_len_in_place(words: &mut [Word], mult: Word, rhs: &[Word])
 ///
 /// Returns carry.
 #[must_use]
-fn add_mul_word_in_place(words: &mut [Word], mult: Word, rhs: &[Word]) -> Word {
+pub(crate) fn add_mul_word_in_place(words: &mut [Word], mult: Word, rhs: &[Word]) -> Word {
     assert!(words.len() >= rhs.len());
     let n = rhs.len();
     let mut carry = add_mul_word_same_len_in_place(&mut words[..n], mult, rhs);
@@ -49,7 +49,8 @@ pub(crate) fn memory_requirement_up_to(n: usize) -> Layout {
     // So we use 4n + 13 ceil log_2 n.
     //
     // Note: the recurence also works when we transition to Karatsuba, because
-    // Karatsuba memory requirements are smaller.
+    // Karatsuba memory requirements are smaller, even with the parallel top level
+    // (4n + 4 ceil log_2 n).
     let num_words = 4 * n + 13 * (math::ceil_log_2(n) as usize);
     memory::array_layout::<Word>(num_words)
 }
@@ -1,6 +1,7 @@
 //! Multiplication operators.
 
 use crate::{
+    add,
     arch::word::Word,
     buffer::Buffer,
     helper_macros,
@@ -12,18 +13,24 @@ use crate::{
     ubig::{Repr::*, UBig},
 };
 use core::{
+    iter::Product,
     mem,
     ops::{Mul, MulAssign},
 };
 use static_assertions::const_assert;
 
+/// Minimum total length in words of the factors for which [UBig::par_product_of_slice] splits the work
+/// between threads.
+#[cfg(feature = "rayon")]
+const MIN_WORDS_PAR_PRODUCT: usize = 256;
+
 impl Mul<UBig> for UBig {
     type Output = UBig;
 
     #[inline]
     fn mul(self, rhs: UBig) -> UBig {
         match (self.into_repr(), rhs.into_repr()) {
-            (Small(word0), Small(word1)) => UBig::mul_word(word0, word1),
+            (Small(word0), Small(word1)) => UBig::mul_word_word(word0, word1),
             (Small(word0), Large(buffer1)) => UBig::mul_large_word(buffer1, word0),
             (Large(buffer0), Small(word1)) => UBig::mul_large_word(buffer0, word1),
             (Large(buffer0), Large(buffer1)) => UBig::mul_large(&buffer0, &buffer1),
@@ -37,7 +44,7 @@ impl Mul<&UBig> for UBig {
     #[inline]
     fn mul(self, rhs: &UBig) -> UBig {
         match (self.into_repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::mul_word(word0, *word1),
+            (Small(word0), Small(word1)) => UBig::mul_word_word(word0, *word1),
             (Small(word0), Large(buffer1)) => UBig::mul_large_word(buffer1.clone(), word0),
             (Large(buffer0), Small(word1)) => UBig::mul_large_word(buffer0, *word1),
             (Large(buffer0), Large(buffer1)) => UBig::mul_large(&buffer0, buffer1),
@@ -60,7 +67,7 @@ impl Mul<&UBig> for &UBig {
     #[inline]
     fn mul(self, rhs: &UBig) -> UBig {
         match (self.repr(), rhs.repr()) {
-            (Small(word0), Small(word1)) => UBig::mul_word(*word0, *word1),
+            (Small(word0), Small(word1)) => UBig::mul_word_word(*word0, *word1),
             (Small(word0), Large(buffer1)) => UBig::mul_large_word(buffer1.clone(), *word0),
             (Large(buffer0), Small(word1)) => UBig::mul_large_word(buffer0.clone(), *word1),
             (Large(buffer0), Large(buffer1)) => UBig::mul_large(buffer0, buffer1),
@@ -138,6 +145,46 @@ impl MulAssign<&IBig> for IBig {
     }
 }
 
+impl Product<UBig> for UBig {
+    fn product<I: Iterator<Item = UBig>>(iter: I) -> UBig {
+        let mut res = UBig::one();
+        for x in iter {
+            res *= x;
+        }
+        res
+    }
+}
+
+impl<'a> Product<&'a UBig> for UBig {
+    fn product<I: Iterator<Item = &'a UBig>>(iter: I) -> UBig {
+        let mut res = UBig::one();
+        for x in iter {
+            res *= x;
+        }
+        res
+    }
+}
+
+impl Product<IBig> for IBig {
+    fn product<I: Iterator<Item = IBig>>(iter: I) -> IBig {
+        let mut res = IBig::one();
+        for x in iter {
+            res *= x;
+        }
+        res
+    }
+}
+
+impl<'a> Product<&'a IBig> for IBig {
+    fn product<I: Iterator<Item = &'a IBig>>(iter: I) -> IBig {
+        let mut res = IBig::one();
+        for x in iter {
+            res *= x;
+        }
+        res
+    }
+}
+
 impl Mul<Sign> for Sign {
     type Output = Sign;
 
@@ -299,9 +346,187 @@ impl_mul_ibig_primitive!(i128);
 impl_mul_ibig_primitive!(isize);
 
 impl UBig {
+    /// Checked multiplication.
+    ///
+    /// Returns `None` if the result would have more than [UBig::MAX_BIT_LEN] bits.
+    /// The `*` operator panics in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).checked_mul(&ubig!(3)), Some(ubig!(15)));
+    /// ```
+    pub fn checked_mul(&self, rhs: &UBig) -> Option<UBig> {
+        let (bits0, bits1) = (self.bit_len(), rhs.bit_len());
+        if bits0 == 0 || bits1 == 0 {
+            return Some(UBig::from_word(0));
+        }
+        // The product has bits0 + bits1 - 1 or bits0 + bits1 bits.
+        if bits0 - 1 > UBig::MAX_BIT_LEN - bits1 {
+            return None;
+        }
+        if bits0 <= UBig::MAX_BIT_LEN - bits1 {
+            return Some(self * rhs);
+        }
+        // floor(a * b / 2) == floor(a / 2) * b + (a mod 2) * floor(b / 2) never overflows.
+        let mut half = (self >> 1) * rhs;
+        if self.bit(0) {
+            half += rhs >> 1;
+        }
+        if half.bit_len() >= UBig::MAX_BIT_LEN {
+            None
+        } else {
+            Some((half << 1) + UBig::from(self.bit(0) && rhs.bit(0)))
+        }
+    }
+
+    /// Fused multiply-add: `self * factor + addend`.
+    ///
+    /// The product is accumulated directly into a copy of `addend`, without allocating
+    /// a separate buffer for the product.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(5).mul_add(&ubig!(3), &ubig!(2)), ubig!(17));
+    /// ```
+    pub fn mul_add(&self, factor: &UBig, addend: &UBig) -> UBig {
+        match (self.repr(), factor.repr()) {
+            (Large(a), Large(b)) => UBig::add_signed_mul_large(addend.as_words(), Positive, a, b).1,
+            _ => self * factor + addend,
+        }
+    }
+
+    /// Evaluate a polynomial at `x` using Horner's method.
+    ///
+    /// `coeffs` are ordered from the highest degree to the constant term. An empty slice is
+    /// the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// // 2x^2 + 3x + 4 at x = 10
+    /// assert_eq!(UBig::eval_poly(&[ubig!(2), ubig!(3), ubig!(4)], &ubig!(10)), ubig!(234));
+    /// ```
+    pub fn eval_poly(coeffs: &[UBig], x: &UBig) -> UBig {
+        match coeffs.split_first() {
+            None => UBig::from_word(0),
+            Some((first, rest)) => rest.iter().fold(first.clone(), |acc, c| acc.mul_add(x, c)),
+        }
+    }
+
+    /// Product of all `factors`, multiplied in a balanced binary tree.
+    ///
+    /// Multiplying one factor at a time, as [Iterator::product] does, makes one operand much
+    /// longer than the other, which defeats the fast multiplication algorithms. Splitting the factors in half recursively
+    /// keeps the operands of similar length. An empty slice gives 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::product_of_slice(&[ubig!(2), ubig!(3), ubig!(7)]), ubig!(42));
+    /// assert_eq!(UBig::product_of_slice(&[]), ubig!(1));
+    /// ```
+    pub fn product_of_slice(factors: &[UBig]) -> UBig {
+        match factors.len() {
+            0 => UBig::from_word(1),
+            1 => factors[0].clone(),
+            len => {
+                let (lo, hi) = factors.split_at(len / 2);
+                UBig::product_of_slice(lo) * UBig::product_of_slice(hi)
+            }
+        }
+    }
+
+    /// Product of all `factors`, multiplied in a balanced binary tree in parallel.
+    ///
+    /// Gives the same result as [UBig::product_of_slice]. The two halves of the tree are multiplied on
+    /// separate threads of the rayon thread pool, down to a minimum size below which they are
+    /// multiplied serially.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// let factors: Vec<UBig> = (1..=20u8).map(UBig::from).collect();
+    /// assert_eq!(UBig::par_product_of_slice(&factors), ubig!(2432902008176640000));
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_product_of_slice(factors: &[UBig]) -> UBig {
+        if factors.len() < 2 {
+            return UBig::product_of_slice(factors);
+        }
+        let total_words: usize = factors.iter().map(|f| f.as_words().len()).sum();
+        if total_words < MIN_WORDS_PAR_PRODUCT || rayon::current_num_threads() <= 1 {
+            return UBig::product_of_slice(factors);
+        }
+        let (lo, hi) = factors.split_at(factors.len() / 2);
+        let (lo, hi) = rayon::join(
+            || UBig::par_product_of_slice(lo),
+            || UBig::par_product_of_slice(hi),
+        );
+        lo * hi
+    }
+
+    /// c + sign * a * b
+    ///
+    /// Returns the sign and magnitude of the result.
+    fn add_signed_mul_large(c: &[Word], sign: Sign, a: &[Word], b: &[Word]) -> (Sign, UBig) {
+        debug_assert!(a.len() >= 2 && b.len() >= 2);
+
+        let mul_len = a.len() + b.len();
+        // One extra word so that positive results never overflow.
+        let res_len = mul_len.max(c.len()) + 1;
+        let mut buffer = Buffer::allocate(res_len);
+        buffer.extend_from_slice(c);
+        buffer.push_zeros(res_len - c.len());
+
+        let mut allocation =
+            MemoryAllocation::new(mul::memory_requirement_exact(mul_len, a.len().min(b.len())));
+        let mut memory = allocation.memory();
+        let carry = mul::add_signed_mul(&mut buffer[..mul_len], sign, a, b, &mut memory);
+        let overflow = add::add_signed_word_in_place(&mut buffer[mul_len..], carry);
+        debug_assert!(overflow == 0 || overflow == -1);
+        if overflow == 0 {
+            (Positive, buffer.into())
+        } else {
+            // The buffer holds the result modulo 2^(res_len * WORD_BITS), negate it.
+            for word in buffer.iter_mut() {
+                *word = !*word;
+            }
+            let overflow = add::add_one_in_place(&mut buffer);
+            debug_assert!(!overflow);
+            (Negative, buffer.into())
+        }
+    }
+
+    /// Multiply by a [Word] in place.
+    ///
+    /// `rhs` is a raw machine word, not a decimal digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(_0x10000000000000000);
+    /// a.mul_word(10);
+    /// assert_eq!(a, ubig!(_0xa0000000000000000));
+    /// ```
+    #[inline]
+    pub fn mul_word(&mut self, rhs: Word) {
+        *self = match mem::take(self).into_repr() {
+            Small(word) => UBig::mul_word_word(word, rhs),
+            Large(buffer) => UBig::mul_large_word(buffer, rhs),
+        }
+    }
+
     /// Multiply two `Word`s.
     #[inline]
-    fn mul_word(a: Word, b: Word) -> UBig {
+    fn mul_word_word(a: Word, b: Word) -> UBig {
         UBig::from(extend_word(a) * extend_word(b))
     }
 
@@ -372,6 +597,46 @@ impl UBig {
 }
 
 impl IBig {
+    /// Fused multiply-add: `self * factor + addend`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(5).mul_add(&ibig!(-3), &ibig!(2)), ibig!(-13));
+    /// ```
+    pub fn mul_add(&self, factor: &IBig, addend: &IBig) -> IBig {
+        match (self.magnitude().repr(), factor.magnitude().repr()) {
+            (Large(a), Large(b)) => {
+                // addend + p = sign(addend) * (|addend| + sign(addend) * p)
+                let product_sign = self.sign() * factor.sign() * addend.sign();
+                let (sign, magnitude) =
+                    UBig::add_signed_mul_large(addend.magnitude().as_words(), product_sign, a, b);
+                IBig::from_sign_magnitude(addend.sign() * sign, magnitude)
+            }
+            _ => self * factor + addend,
+        }
+    }
+
+    /// Evaluate a polynomial at `x` using Horner's method.
+    ///
+    /// `coeffs` are ordered from the highest degree to the constant term. An empty slice is
+    /// the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// // 2x^2 - 3x + 4 at x = -10
+    /// assert_eq!(IBig::eval_poly(&[ibig!(2), ibig!(-3), ibig!(4)], &ibig!(-10)), ibig!(234));
+    /// ```
+    pub fn eval_poly(coeffs: &[IBig], x: &IBig) -> IBig {
+        match coeffs.split_first() {
+            None => IBig::from(0u8),
+            Some((first, rest)) => rest.iter().fold(first.clone(), |acc, c| acc.mul_add(x, c)),
+        }
+    }
+
     #[inline]
     fn mul_primitive<T>(self, rhs: T) -> IBig
     where
@@ -5,38 +5,48 @@ use crate::{error::ParseError, ibig::IBig, ops::Abs, ubig::UBig};
 impl num_traits::Zero for UBig {
     #[inline]
     fn zero() -> Self {
-        Self::from(0u8)
+        UBig::zero()
     }
 
     #[inline]
     fn is_zero(&self) -> bool {
-        *self == Self::from(0u8)
+        UBig::is_zero(self)
     }
 }
 
 impl num_traits::Zero for IBig {
     #[inline]
     fn zero() -> Self {
-        Self::from(0u8)
+        IBig::zero()
     }
 
     #[inline]
     fn is_zero(&self) -> bool {
-        *self == Self::from(0u8)
+        IBig::is_zero(self)
     }
 }
 
 impl num_traits::One for UBig {
     #[inline]
     fn one() -> Self {
-        Self::from(1u8)
+        UBig::one()
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        UBig::is_one(self)
     }
 }
 
 impl num_traits::One for IBig {
     #[inline]
     fn one() -> Self {
-        Self::from(1u8)
+        IBig::one()
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        IBig::is_one(self)
     }
 }
 
@@ -45,7 +55,7 @@ impl num_traits::Pow<usize> for UBig {
 
     #[inline]
     fn pow(self, rhs: usize) -> UBig {
-        (&self).pow(rhs)
+        UBig::pow(&self, rhs)
     }
 }
 
@@ -63,7 +73,7 @@ impl num_traits::Pow<usize> for IBig {
 
     #[inline]
     fn pow(self, rhs: usize) -> IBig {
-        (&self).pow(rhs)
+        IBig::pow(&self, rhs)
     }
 }
 
@@ -96,12 +106,12 @@ impl num_traits::Signed for IBig {
 
     #[inline]
     fn is_positive(&self) -> bool {
-        *self > IBig::from(0u8)
+        IBig::is_positive(self)
     }
 
     #[inline]
     fn is_negative(&self) -> bool {
-        *self < IBig::from(0u8)
+        IBig::is_negative(self)
     }
 }
 
@@ -3,14 +3,17 @@
 use crate::{
     error::ParseError,
     ibig::IBig,
+    primitive::WORD_BITS_USIZE,
     radix::{self, Digit},
-    sign::Sign::*,
+    sign::Sign::{self, *},
     ubig::UBig,
 };
 use core::str::FromStr;
 
 mod non_power_two;
 mod power_two;
+#[cfg(feature = "std")]
+mod reader;
 
 impl FromStr for UBig {
     type Err = ParseError;
@@ -46,8 +49,110 @@ impl UBig {
     /// ```
     pub fn from_str_radix(src: &str, radix: u32) -> Result<UBig, ParseError> {
         radix::check_radix_valid(radix);
-        let src = src.strip_prefix('+').unwrap_or(src);
-        UBig::from_str_radix_no_sign(src, radix)
+        let unsigned = src.strip_prefix('+').unwrap_or(src);
+        UBig::from_str_radix_no_sign(unsigned, radix, src.len() - unsigned.len())
+    }
+
+    /// Convert a string in a given base to [UBig], ignoring surrounding whitespace.
+    ///
+    /// Like [UBig::from_str_radix], but leading and trailing ASCII whitespace is removed before
+    /// the `+` prefix. Whitespace between the digits is still an error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{error::ParseError, ubig, UBig};
+    /// assert_eq!(UBig::from_str_radix_trimmed(" +123\n", 10)?, ubig!(123));
+    /// assert_eq!(
+    ///     UBig::from_str_radix_trimmed(" 1 2", 10),
+    ///     Err(ParseError::InvalidDigit { character: ' ', position: 2 })
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_radix_trimmed(src: &str, radix: u32) -> Result<UBig, ParseError> {
+        radix::check_radix_valid(radix);
+        let (trimmed, leading) = trim_ascii_whitespace(src);
+        let unsigned = trimmed.strip_prefix('+').unwrap_or(trimmed);
+        UBig::from_str_radix_no_sign(unsigned, radix, leading + trimmed.len() - unsigned.len())
+    }
+
+    /// Convert a string in a given base to [UBig], rejecting numbers longer than `max_bits`.
+    ///
+    /// Like [UBig::from_str_radix], but suitable for untrusted input: strings with so many
+    /// digits that the number certainly exceeds `max_bits` are rejected before any conversion
+    /// work or allocation. Numbers that do fit are never rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ParseError::TooLarge] if the number has more than `max_bits` bits. The length
+    /// check may happen before the digits are validated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{error::ParseError, ubig, UBig};
+    /// assert_eq!(UBig::from_str_radix_bounded("255", 10, 8)?, ubig!(255));
+    /// assert_eq!(UBig::from_str_radix_bounded("256", 10, 8), Err(ParseError::TooLarge));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_radix_bounded(
+        src: &str,
+        radix: u32,
+        max_bits: usize,
+    ) -> Result<UBig, ParseError> {
+        radix::check_radix_valid(radix);
+        let unsigned = src.strip_prefix('+').unwrap_or(src);
+        let num_digits = unsigned.trim_start_matches('0').len();
+        if num_digits > 1 {
+            // A number with d digits is at least radix^(d-1), and
+            // log2(radix) > WORD_BITS / (digits_per_word + 1).
+            let min_bits = (num_digits - 1).saturating_mul(WORD_BITS_USIZE)
+                / (radix::radix_info(radix).digits_per_word + 1)
+                + 1;
+            if min_bits > max_bits {
+                return Err(ParseError::TooLarge);
+            }
+        }
+        let res = UBig::from_str_radix_no_sign(unsigned, radix, src.len() - unsigned.len())?;
+        if res.bit_len() > max_bits {
+            return Err(ParseError::TooLarge);
+        }
+        Ok(res)
+    }
+
+    /// Convert a string in a given base to [UBig], allowing `_` separators between digits.
+    ///
+    /// Like [UBig::from_str_radix], but digits may be separated by single underscores, as in
+    /// Rust integer literals.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ParseError::InvalidSeparator] for a leading, trailing or doubled `_`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{error::ParseError, ubig, UBig};
+    /// assert_eq!(UBig::from_str_radix_separated("1_000_000", 10)?, ubig!(1000000));
+    /// assert_eq!(
+    ///     UBig::from_str_radix_separated("1__000", 10),
+    ///     Err(ParseError::InvalidSeparator { position: 2 })
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_radix_separated(src: &str, radix: u32) -> Result<UBig, ParseError> {
+        radix::check_radix_valid(radix);
+        let unsigned = src.strip_prefix('+').unwrap_or(src);
+        UBig::from_str_radix_separated_no_sign(unsigned, radix, src.len() - unsigned.len())
     }
 
     /// Convert a string with an optional radix prefix to [UBig].
@@ -65,40 +170,159 @@ impl UBig {
     /// # Ok::<(), ParseError>(())
     /// ```
     pub fn from_str_with_radix_prefix(src: &str) -> Result<UBig, ParseError> {
-        let src = src.strip_prefix('+').unwrap_or(src);
-        UBig::from_str_with_radix_prefix_no_sign(src)
+        let unsigned = src.strip_prefix('+').unwrap_or(src);
+        UBig::from_str_with_radix_prefix_no_sign(unsigned, src.len() - unsigned.len(), false)
+    }
+
+    /// Convert a string with an optional radix prefix to [UBig], allowing `_` separators
+    /// between digits.
+    ///
+    /// Like [UBig::from_str_with_radix_prefix], but digits after the prefix may be separated
+    /// by single underscores, as in Rust integer literals.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ParseError::InvalidSeparator] for a leading, trailing or doubled `_`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{error::ParseError, ubig, UBig};
+    /// assert_eq!(UBig::from_str_with_radix_prefix_separated("0xff_ff")?, ubig!(0xffff));
+    /// assert_eq!(UBig::from_str_with_radix_prefix_separated("1_000")?, ubig!(1000));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_with_radix_prefix_separated(src: &str) -> Result<UBig, ParseError> {
+        let unsigned = src.strip_prefix('+').unwrap_or(src);
+        UBig::from_str_with_radix_prefix_no_sign(unsigned, src.len() - unsigned.len(), true)
     }
 
     /// Convert an unsigned string with an optional radix prefix to [UBig].
-    fn from_str_with_radix_prefix_no_sign(src: &str) -> Result<UBig, ParseError> {
-        if let Some(bin) = src.strip_prefix("0b") {
-            UBig::from_str_radix_no_sign(bin, 2)
+    ///
+    /// `src` starts at byte `offset` of the input.
+    fn from_str_with_radix_prefix_no_sign(
+        src: &str,
+        offset: usize,
+        separated: bool,
+    ) -> Result<UBig, ParseError> {
+        let (digits, radix, offset) = if let Some(bin) = src.strip_prefix("0b") {
+            (bin, 2, offset + 2)
         } else if let Some(oct) = src.strip_prefix("0o") {
-            UBig::from_str_radix_no_sign(oct, 8)
+            (oct, 8, offset + 2)
         } else if let Some(hex) = src.strip_prefix("0x") {
-            UBig::from_str_radix_no_sign(hex, 16)
+            (hex, 16, offset + 2)
+        } else {
+            (src, 10, offset)
+        };
+        if separated {
+            UBig::from_str_radix_separated_no_sign(digits, radix, offset)
         } else {
-            UBig::from_str_radix_no_sign(src, 10)
+            UBig::from_str_radix_no_sign(digits, radix, offset)
         }
     }
 
     /// Convert an unsigned string to [UBig].
-    fn from_str_radix_no_sign(mut src: &str, radix: Digit) -> Result<UBig, ParseError> {
+    ///
+    /// `src` starts at byte `offset` of the input.
+    fn from_str_radix_no_sign(src: &str, radix: Digit, offset: usize) -> Result<UBig, ParseError> {
+        // The digit parsers skip `_`.
+        if src.as_bytes().contains(&b'_') {
+            return Err(invalid_digit_error(src, radix, offset));
+        }
+        UBig::from_digits_str(src, radix, offset)
+    }
+
+    /// Convert an unsigned string with `_` separators to [UBig].
+    ///
+    /// `src` starts at byte `offset` of the input.
+    fn from_str_radix_separated_no_sign(
+        src: &str,
+        radix: Digit,
+        offset: usize,
+    ) -> Result<UBig, ParseError> {
+        check_separators(src, radix, offset)?;
+        UBig::from_digits_str(src, radix, offset)
+    }
+
+    /// Convert an unsigned string to [UBig], skipping any `_`.
+    ///
+    /// `src` starts at byte `offset` of the input.
+    fn from_digits_str(src: &str, radix: Digit, offset: usize) -> Result<UBig, ParseError> {
         debug_assert!(radix::is_radix_valid(radix));
         if src.is_empty() {
             return Err(ParseError::NoDigits);
         }
 
-        while let Some(src2) = src.strip_prefix('0') {
-            src = src2;
+        let digits = src.trim_start_matches(|c| c == '0' || c == '_');
+        if digits.is_empty() {
+            return Ok(UBig::from_word(0));
         }
+        let res = if radix.is_power_of_two() {
+            power_two::parse(digits, radix)
+        } else {
+            non_power_two::parse(digits, radix)
+        };
+        res.ok_or_else(|| invalid_digit_error(src, radix, offset))
+    }
+}
 
-        if radix.is_power_of_two() {
-            power_two::parse(src, radix)
+/// Remove leading and trailing ASCII whitespace.
+///
+/// Returns the trimmed string and the number of bytes removed from the front.
+fn trim_ascii_whitespace(src: &str) -> (&str, usize) {
+    let trimmed = src.trim_start_matches(|c: char| c.is_ascii_whitespace());
+    let leading = src.len() - trimmed.len();
+    (
+        trimmed.trim_end_matches(|c: char| c.is_ascii_whitespace()),
+        leading,
+    )
+}
+
+/// Check that `_` separators are only between digits.
+///
+/// `src` starts at byte `offset` of the input. Also checks that all other characters are
+/// valid digits, so that an invalid digit before a misplaced separator is reported first.
+fn check_separators(src: &str, radix: Digit, offset: usize) -> Result<(), ParseError> {
+    // Treat the start as a separator, so that a leading `_` is rejected.
+    let mut after_separator = true;
+    for (position, c) in src.char_indices() {
+        if c == '_' {
+            if after_separator {
+                return Err(ParseError::InvalidSeparator {
+                    position: offset + position,
+                });
+            }
+            after_separator = true;
         } else {
-            non_power_two::parse(src, radix)
+            if c.to_digit(radix).is_none() {
+                return Err(ParseError::InvalidDigit {
+                    character: c,
+                    position: offset + position,
+                });
+            }
+            after_separator = false;
         }
     }
+    if after_separator && !src.is_empty() {
+        return Err(ParseError::InvalidSeparator {
+            position: offset + src.len() - 1,
+        });
+    }
+    Ok(())
+}
+
+/// Error for the first invalid digit in `src`, which starts at byte `offset` of the input.
+///
+/// Only called on the error path, so that parsing doesn't have to track positions.
+fn invalid_digit_error(src: &str, radix: Digit, offset: usize) -> ParseError {
+    let (position, character) = src
+        .char_indices()
+        .find(|&(_, c)| c.to_digit(radix).is_none())
+        .expect("no invalid digit");
+    ParseError::InvalidDigit {
+        character,
+        position: offset + position,
+    }
 }
 
 impl IBig {
@@ -119,18 +343,57 @@ impl IBig {
     /// ```
     pub fn from_str_radix(mut src: &str, radix: u32) -> Result<IBig, ParseError> {
         radix::check_radix_valid(radix);
-        let sign;
-        match src.strip_prefix('-') {
-            Some(s) => {
-                sign = Negative;
-                src = s;
-            }
-            None => {
-                sign = Positive;
-                src = src.strip_prefix('+').unwrap_or(src);
-            }
-        }
-        let mag = UBig::from_str_radix_no_sign(src, radix)?;
+        let (sign, offset) = IBig::strip_sign(&mut src);
+        let mag = UBig::from_str_radix_no_sign(src, radix, offset)?;
+        Ok(IBig::from_sign_magnitude(sign, mag))
+    }
+
+    /// Convert a string in a given base to [IBig], ignoring surrounding whitespace.
+    ///
+    /// Like [IBig::from_str_radix], but leading and trailing ASCII whitespace is removed before
+    /// the sign. Whitespace between the digits is still an error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{error::ParseError, ibig, IBig};
+    /// assert_eq!(IBig::from_str_radix_trimmed("\t-ff ", 16)?, ibig!(-0xff));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_radix_trimmed(src: &str, radix: u32) -> Result<IBig, ParseError> {
+        radix::check_radix_valid(radix);
+        let (mut trimmed, leading) = trim_ascii_whitespace(src);
+        let (sign, offset) = IBig::strip_sign(&mut trimmed);
+        let mag = UBig::from_str_radix_no_sign(trimmed, radix, leading + offset)?;
+        Ok(IBig::from_sign_magnitude(sign, mag))
+    }
+
+    /// Convert a string in a given base to [IBig], allowing `_` separators between digits.
+    ///
+    /// Like [IBig::from_str_radix], but digits may be separated by single underscores, as in
+    /// Rust integer literals.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ParseError::InvalidSeparator] for a leading, trailing or doubled `_`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::{error::ParseError, ibig, IBig};
+    /// assert_eq!(IBig::from_str_radix_separated("-ff_ff", 16)?, ibig!(-0xffff));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_radix_separated(mut src: &str, radix: u32) -> Result<IBig, ParseError> {
+        radix::check_radix_valid(radix);
+        let (sign, offset) = IBig::strip_sign(&mut src);
+        let mag = UBig::from_str_radix_separated_no_sign(src, radix, offset)?;
         Ok(IBig::from_sign_magnitude(sign, mag))
     }
 
@@ -148,18 +411,49 @@ impl IBig {
     /// # Ok::<(), ParseError>(())
     /// ```
     pub fn from_str_with_radix_prefix(mut src: &str) -> Result<IBig, ParseError> {
-        let sign;
-        match src.strip_prefix('-') {
+        let (sign, offset) = IBig::strip_sign(&mut src);
+        let mag = UBig::from_str_with_radix_prefix_no_sign(src, offset, false)?;
+        Ok(IBig::from_sign_magnitude(sign, mag))
+    }
+
+    /// Convert a string with an optional radix prefix to [IBig], allowing `_` separators
+    /// between digits.
+    ///
+    /// Like [IBig::from_str_with_radix_prefix], but digits after the prefix may be separated
+    /// by single underscores, as in Rust integer literals.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ParseError::InvalidSeparator] for a leading, trailing or doubled `_`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{error::ParseError, ibig, IBig};
+    /// assert_eq!(IBig::from_str_with_radix_prefix_separated("-0xff_ff")?, ibig!(-0xffff));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_with_radix_prefix_separated(mut src: &str) -> Result<IBig, ParseError> {
+        let (sign, offset) = IBig::strip_sign(&mut src);
+        let mag = UBig::from_str_with_radix_prefix_no_sign(src, offset, true)?;
+        Ok(IBig::from_sign_magnitude(sign, mag))
+    }
+
+    /// Remove the `+` or `-` prefix from `src`.
+    ///
+    /// Returns the sign and the length of the prefix.
+    fn strip_sign(src: &mut &str) -> (Sign, usize) {
+        let len = src.len();
+        let sign = match src.strip_prefix('-') {
             Some(s) => {
-                sign = Negative;
-                src = s;
+                *src = s;
+                Negative
             }
             None => {
-                sign = Positive;
-                src = src.strip_prefix('+').unwrap_or(src);
+                *src = src.strip_prefix('+').unwrap_or(src);
+                Positive
             }
-        }
-        let mag = UBig::from_str_with_radix_prefix_no_sign(src)?;
-        Ok(IBig::from_sign_magnitude(sign, mag))
+        };
+        (sign, len - src.len())
     }
 }
@@ -3,7 +3,6 @@
 use crate::{
     arch::word::Word,
     buffer::Buffer,
-    error::ParseError,
     mul,
     radix::{self, Digit},
     ubig::UBig,
@@ -14,104 +13,151 @@ use alloc::vec;
 const CHUNK_LEN: usize = 256;
 
 /// Parse an unsigned string to [UBig].
-pub(crate) fn parse(src: &str, radix: Digit) -> Result<UBig, ParseError> {
+///
+/// `_` bytes are skipped; they have already been validated as separators.
+///
+/// Returns `None` if there is an invalid digit.
+pub(crate) fn parse(src: &str, radix: Digit) -> Option<UBig> {
     debug_assert!(radix::is_radix_valid(radix) && !radix.is_power_of_two());
     let radix_info = radix::radix_info(radix);
     let bytes = src.as_bytes();
+    let num_digits = bytes.iter().filter(|&&byte| byte != b'_').count();
 
-    if bytes.len() <= radix_info.digits_per_word {
+    if num_digits <= radix_info.digits_per_word {
         let word = parse_word(bytes, radix)?;
-        Ok(UBig::from_word(word))
-    } else if bytes.len() <= CHUNK_LEN * radix_info.digits_per_word {
-        parse_chunk(bytes, radix)
+        Some(UBig::from_word(word))
+    } else if num_digits <= CHUNK_LEN * radix_info.digits_per_word {
+        parse_chunk(bytes, num_digits, radix)
     } else {
-        parse_large(bytes, radix)
+        parse_large(bytes, num_digits, radix)
     }
 }
 
 /// Parse an unsigned string to `Word`.
 ///
-/// The length of the string must be at most `digits_per_word`.
-fn parse_word(src: &[u8], radix: Digit) -> Result<Word, ParseError> {
+/// The number of digits must be at most `digits_per_word`.
+fn parse_word(src: &[u8], radix: Digit) -> Option<Word> {
     debug_assert!(radix::is_radix_valid(radix) && !radix.is_power_of_two());
-    debug_assert!(src.len() <= radix::radix_info(radix).digits_per_word);
 
     let mut word: Word = 0;
     for byte in src.iter() {
-        let digit = radix::digit_from_utf8_byte(*byte, radix).ok_or(ParseError::InvalidDigit)?;
+        if *byte == b'_' {
+            continue;
+        }
+        let digit = radix::digit_from_utf8_byte(*byte, radix)?;
         word = word * (radix as Word) + (digit as Word);
     }
-    Ok(word)
+    Some(word)
 }
 
-/// Parse an unsigned string to [UBig].
+/// Parse an unsigned string with `num_digits` digits to [UBig].
 ///
-/// The length of input is limited to `CHUNK_LEN * digits_per_word`.
-fn parse_chunk(bytes: &[u8], radix: Digit) -> Result<UBig, ParseError> {
+/// The number of digits is limited to `CHUNK_LEN * digits_per_word`.
+fn parse_chunk(bytes: &[u8], num_digits: usize, radix: Digit) -> Option<UBig> {
     debug_assert!(radix::is_radix_valid(radix) && !radix.is_power_of_two());
     let radix_info = radix::radix_info(radix);
-    debug_assert!(bytes.len() <= CHUNK_LEN * radix_info.digits_per_word);
+    debug_assert!(num_digits <= CHUNK_LEN * radix_info.digits_per_word);
 
-    let groups = bytes.rchunks(radix_info.digits_per_word);
-    let mut buffer = Buffer::allocate(groups.len());
-    for group in groups.rev() {
-        let next = parse_word(group, radix)?;
-        let carry = mul::mul_word_in_place_with_carry(&mut buffer, radix_info.range_per_word, next);
-        if carry != 0 {
-            buffer.push(carry);
+    let num_groups = (num_digits + radix_info.digits_per_word - 1) / radix_info.digits_per_word;
+    let mut buffer = Buffer::allocate(num_groups);
+    // Only the most significant group may be shorter than digits_per_word.
+    let mut group_len = num_digits - (num_groups - 1) * radix_info.digits_per_word;
+    let mut group_digits = 0;
+    let mut word: Word = 0;
+    for byte in bytes.iter() {
+        if *byte == b'_' {
+            continue;
+        }
+        let digit = radix::digit_from_utf8_byte(*byte, radix)?;
+        word = word * (radix as Word) + (digit as Word);
+        group_digits += 1;
+        if group_digits == group_len {
+            let carry =
+                mul::mul_word_in_place_with_carry(&mut buffer, radix_info.range_per_word, word);
+            if carry != 0 {
+                buffer.push(carry);
+            }
+            word = 0;
+            group_digits = 0;
+            group_len = radix_info.digits_per_word;
         }
     }
-    Ok(buffer.into())
+    Some(buffer.into())
 }
 
-/// Parse an unsigned string to [UBig].
+/// Parse an unsigned string with `num_digits` digits to [UBig].
 ///
 /// This result will usually not fit in CHUNK_LEN words.
-fn parse_large(bytes: &[u8], radix: Digit) -> Result<UBig, ParseError> {
+fn parse_large(bytes: &[u8], num_digits: usize, radix: Digit) -> Option<UBig> {
     debug_assert!(radix::is_radix_valid(radix) && !radix.is_power_of_two());
     let radix_info = radix::radix_info(radix);
-    let chunk_bytes = CHUNK_LEN * radix_info.digits_per_word;
-    assert!(bytes.len() > chunk_bytes);
+    let chunk_digits = CHUNK_LEN * radix_info.digits_per_word;
+    assert!(num_digits > chunk_digits);
 
     // Calculate radix^(CHUNK_LEN<<i).
     let mut radix_powers = vec![UBig::from_word(radix_info.range_per_word).pow(CHUNK_LEN)];
 
-    // while (chunk_bytes << radix_powers.len()) < bytes.len()
+    // while (chunk_digits << radix_powers.len()) < num_digits
     // To avoid overflow:
-    while chunk_bytes <= (bytes.len() - 1) >> radix_powers.len() {
+    while chunk_digits <= (num_digits - 1) >> radix_powers.len() {
         let prev = radix_powers.last().unwrap();
         let new = prev * prev;
         radix_powers.push(new);
     }
 
-    parse_large_divide_conquer(bytes, radix, chunk_bytes, &radix_powers)
+    parse_large_divide_conquer(bytes, num_digits, radix, chunk_digits, &radix_powers)
 }
 
-/// Convert an unsigned string to [UBig].
+/// Convert an unsigned string with `num_digits` digits to [UBig].
 ///
 /// `radix_powers` contains radix^n for n = chunk digits << i
 fn parse_large_divide_conquer(
     bytes: &[u8],
+    num_digits: usize,
     radix: Digit,
-    chunk_bytes: usize,
+    chunk_digits: usize,
     radix_powers: &[UBig],
-) -> Result<UBig, ParseError> {
-    debug_assert!(bytes.len() <= chunk_bytes << radix_powers.len());
+) -> Option<UBig> {
+    debug_assert!(num_digits <= chunk_digits << radix_powers.len());
 
     match radix_powers.split_last() {
-        None => parse_chunk(bytes, radix),
+        None => parse_chunk(bytes, num_digits, radix),
         Some((radix_power, radix_powers)) => {
-            let bytes_lo_len = chunk_bytes << radix_powers.len();
-            if bytes.len() <= bytes_lo_len {
-                parse_large_divide_conquer(bytes, radix, chunk_bytes, radix_powers)
+            let digits_lo_len = chunk_digits << radix_powers.len();
+            if num_digits <= digits_lo_len {
+                parse_large_divide_conquer(bytes, num_digits, radix, chunk_digits, radix_powers)
             } else {
-                let (bytes_hi, bytes_lo) = bytes.split_at(bytes.len() - bytes_lo_len);
-                let res_hi =
-                    parse_large_divide_conquer(bytes_hi, radix, chunk_bytes, radix_powers)?;
-                let res_lo =
-                    parse_large_divide_conquer(bytes_lo, radix, chunk_bytes, radix_powers)?;
-                Ok(res_hi * radix_power + res_lo)
+                let (bytes_hi, bytes_lo) = split_low_digits(bytes, digits_lo_len);
+                let res_hi = parse_large_divide_conquer(
+                    bytes_hi,
+                    num_digits - digits_lo_len,
+                    radix,
+                    chunk_digits,
+                    radix_powers,
+                )?;
+                let res_lo = parse_large_divide_conquer(
+                    bytes_lo,
+                    digits_lo_len,
+                    radix,
+                    chunk_digits,
+                    radix_powers,
+                )?;
+                Some(res_hi * radix_power + res_lo)
             }
         }
     }
 }
+
+/// Split `bytes` so that the second part contains the last `num_digits` digits, not counting
+/// `_` separators.
+fn split_low_digits(bytes: &[u8], num_digits: usize) -> (&[u8], &[u8]) {
+    let mut digits = 0;
+    let mut index = bytes.len();
+    while digits < num_digits {
+        index -= 1;
+        if bytes[index] != b'_' {
+            digits += 1;
+        }
+    }
+    bytes.split_at(index)
+}
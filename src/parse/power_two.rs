@@ -3,20 +3,23 @@
 use crate::{
     arch::word::Word,
     buffer::Buffer,
-    error::ParseError,
     primitive::{WORD_BITS, WORD_BITS_USIZE},
     radix::{self, Digit},
     ubig::UBig,
 };
 
 /// Parse an unsigned string to [UBig].
-pub(crate) fn parse(src: &str, radix: Digit) -> Result<UBig, ParseError> {
+///
+/// `_` bytes are skipped; they have already been validated as separators.
+///
+/// Returns `None` if there is an invalid digit.
+pub(crate) fn parse(src: &str, radix: Digit) -> Option<UBig> {
     debug_assert!(radix::is_radix_valid(radix) && radix.is_power_of_two());
     let radix_info = radix::radix_info(radix);
 
     if src.len() <= radix_info.digits_per_word {
         let word = parse_word(src, radix)?;
-        Ok(UBig::from_word(word))
+        Some(UBig::from_word(word))
     } else {
         parse_large(src, radix)
     }
@@ -24,8 +27,8 @@ pub(crate) fn parse(src: &str, radix: Digit) -> Result<UBig, ParseError> {
 
 /// Parse an unsigned string to `Word`.
 ///
-/// The length of the string must be at most digits_per_word(radix).
-fn parse_word(src: &str, radix: Digit) -> Result<Word, ParseError> {
+/// The number of digits must be at most digits_per_word(radix).
+fn parse_word(src: &str, radix: Digit) -> Option<Word> {
     debug_assert!(radix::is_radix_valid(radix) && radix.is_power_of_two());
     debug_assert!(src.len() <= radix::radix_info(radix).digits_per_word);
 
@@ -33,17 +36,20 @@ fn parse_word(src: &str, radix: Digit) -> Result<Word, ParseError> {
     let mut word = 0;
     let mut bits = 0;
     for byte in src.as_bytes().iter().rev() {
-        let digit = radix::digit_from_utf8_byte(*byte, radix).ok_or(ParseError::InvalidDigit)?;
+        if *byte == b'_' {
+            continue;
+        }
+        let digit = radix::digit_from_utf8_byte(*byte, radix)?;
         word |= (digit as Word) << bits;
         bits += log_radix;
     }
-    Ok(word)
+    Some(word)
 }
 
 /// Parse an unsigned string to [UBig].
 ///
 /// The result will usually not fit in a single word.
-fn parse_large(src: &str, radix: Digit) -> Result<UBig, ParseError> {
+fn parse_large(src: &str, radix: Digit) -> Option<UBig> {
     debug_assert!(radix::is_radix_valid(radix) && radix.is_power_of_two());
 
     let log_radix = radix.trailing_zeros();
@@ -56,7 +62,10 @@ fn parse_large(src: &str, radix: Digit) -> Result<UBig, ParseError> {
     let mut bits = 0;
     let mut word = 0;
     for byte in src.as_bytes().iter().rev() {
-        let digit = radix::digit_from_utf8_byte(*byte, radix).ok_or(ParseError::InvalidDigit)?;
+        if *byte == b'_' {
+            continue;
+        }
+        let digit = radix::digit_from_utf8_byte(*byte, radix)?;
         word |= (digit as Word) << bits;
         let new_bits = bits + log_radix;
         if new_bits >= WORD_BITS {
@@ -70,5 +79,5 @@ fn parse_large(src: &str, radix: Digit) -> Result<UBig, ParseError> {
     if bits > 0 {
         buffer.push(word);
     }
-    Ok(buffer.into())
+    Some(buffer.into())
 }
@@ -0,0 +1,138 @@
+//! Parsing from a byte stream.
+
+use crate::{
+    arch::word::Word,
+    error::ParseError,
+    radix::{self, Digit},
+    ubig::UBig,
+};
+use std::io::{self, ErrorKind, Read};
+
+/// Size of the chunks read from the stream.
+const CHUNK_LEN: usize = 4096;
+
+impl UBig {
+    /// Parse digits in a given base read from a byte stream.
+    ///
+    /// The stream is read in chunks and digits are accumulated as they arrive, so memory use
+    /// is proportional to the size of the result rather than the size of the input. The
+    /// stream must contain only ASCII digits: no sign and no radix prefix. If
+    /// `skip_whitespace` is true, ASCII whitespace anywhere in the stream is ignored.
+    ///
+    /// I/O errors are returned in the outer `Result`, invalid input in the inner one.
+    /// Error positions are byte offsets in the stream.
+    ///
+    /// Accumulation takes time quadratic in the length of the input. For numbers that fit
+    /// in memory as a string, [UBig::from_str_radix] is faster.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// let input = "1234567890\n1234567890\n";
+    /// let x = UBig::from_reader(input.as_bytes(), 10, true)?;
+    /// assert_eq!(x, Ok(ubig!(12345678901234567890)));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn from_reader<R>(
+        mut reader: R,
+        radix: u32,
+        skip_whitespace: bool,
+    ) -> io::Result<Result<UBig, ParseError>>
+    where
+        R: Read,
+    {
+        radix::check_radix_valid(radix);
+        let mut acc = Accumulator::new(radix);
+        let mut chunk = [0u8; CHUNK_LEN];
+        let mut position = 0;
+        loop {
+            let len = match reader.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(len) => len,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            for &byte in &chunk[..len] {
+                match radix::digit_from_utf8_byte(byte, radix) {
+                    Some(digit) => acc.push(digit),
+                    None if skip_whitespace && byte.is_ascii_whitespace() => {}
+                    None => {
+                        let character = if byte.is_ascii() {
+                            char::from(byte)
+                        } else {
+                            core::char::REPLACEMENT_CHARACTER
+                        };
+                        return Ok(Err(ParseError::InvalidDigit {
+                            character,
+                            position,
+                        }));
+                    }
+                }
+                position += 1;
+            }
+        }
+        Ok(acc.finish())
+    }
+}
+
+/// Horner's method accumulator, one `Word` worth of digits at a time.
+struct Accumulator {
+    radix: Digit,
+    digits_per_word: usize,
+    value: UBig,
+    /// Digits not yet added to `value`.
+    group: Word,
+    group_len: usize,
+    any_digits: bool,
+}
+
+impl Accumulator {
+    fn new(radix: Digit) -> Accumulator {
+        Accumulator {
+            radix,
+            digits_per_word: radix::radix_info(radix).digits_per_word,
+            value: UBig::from_word(0),
+            group: 0,
+            group_len: 0,
+            any_digits: false,
+        }
+    }
+
+    fn push(&mut self, digit: Digit) {
+        self.group = self.group * self.radix as Word + digit as Word;
+        self.group_len += 1;
+        self.any_digits = true;
+        if self.group_len == self.digits_per_word {
+            self.flush();
+        }
+    }
+
+    /// value = value * radix^group_len + group
+    fn flush(&mut self) {
+        if self.group_len == 0 {
+            return;
+        }
+        if self.radix.is_power_of_two() {
+            self.value <<= self.group_len * self.radix.trailing_zeros() as usize;
+        } else {
+            self.value
+                .mul_word((self.radix as Word).pow(self.group_len as u32));
+        }
+        self.value.add_word(self.group);
+        self.group = 0;
+        self.group_len = 0;
+    }
+
+    fn finish(mut self) -> Result<UBig, ParseError> {
+        if !self.any_digits {
+            return Err(ParseError::NoDigits);
+        }
+        self.flush();
+        Ok(self.value)
+    }
+}
@@ -48,6 +48,44 @@ impl UBig {
         }
         res
     }
+
+    /// Checked exponentiation.
+    ///
+    /// Returns `None` if the result would have more than [UBig::MAX_BIT_LEN] bits.
+    /// [UBig::pow] panics in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(3).checked_pow(3), Some(ubig!(27)));
+    /// assert_eq!(ubig!(3).checked_pow(usize::MAX), None);
+    /// ```
+    pub fn checked_pow(&self, exp: usize) -> Option<UBig> {
+        let bits = self.bit_len();
+        if bits <= 1 || exp == 0 {
+            return Some(self.pow(exp));
+        }
+        // The result has between (bits - 1) * exp + 1 and bits * exp bits.
+        match (bits - 1).checked_mul(exp) {
+            Some(min_bits) if min_bits < UBig::MAX_BIT_LEN => {}
+            _ => return None,
+        }
+        match bits.checked_mul(exp) {
+            Some(max_bits) if max_bits <= UBig::MAX_BIT_LEN => return Some(self.pow(exp)),
+            _ => {}
+        }
+        let mut p = usize::BIT_SIZE - 1 - exp.leading_zeros();
+        let mut res = self.clone();
+        while p != 0 {
+            p -= 1;
+            res = res.checked_mul(&res)?;
+            if exp & (1 << p) != 0 {
+                res = res.checked_mul(self)?;
+            }
+        }
+        Some(res)
+    }
 }
 
 impl IBig {
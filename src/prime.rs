@@ -0,0 +1,205 @@
+//! Primality testing.
+
+use crate::{modular::ModuloRing, ubig::UBig};
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+/// Primes used for trial division.
+const SMALL_PRIMES: [u32; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// The Miller-Rabin test with witnesses `SMALL_PRIMES[..NUM_DETERMINISTIC_WITNESSES]` gives a
+/// definite answer for all numbers below `DETERMINISTIC_BOUND`.
+const NUM_DETERMINISTIC_WITNESSES: usize = 13;
+#[cfg(feature = "rand")]
+const DETERMINISTIC_BOUND: u128 = 3_317_044_064_679_887_385_961_981;
+
+/// Trial division by `SMALL_PRIMES` gives a definite answer for all numbers below this bound.
+const TRIAL_DIVISION_BOUND: u32 = 97 * 97;
+
+/// Number of Miller-Rabin rounds in `gen_prime`: a composite passes with probability at most
+/// 2^-64.
+#[cfg(feature = "rand")]
+const GEN_PRIME_ROUNDS: usize = 32;
+
+impl UBig {
+    /// Probabilistic primality test.
+    ///
+    /// Tries division by small primes first, then runs the Miller-Rabin test.
+    ///
+    /// Numbers below 3317044064679887385961981 use a fixed set of witnesses that is known
+    /// to give a definite answer. Larger numbers use `rounds` random witnesses: a prime
+    /// always returns `true`, and a composite number returns `true` with probability
+    /// at most `4^-rounds`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// # use rand::thread_rng;
+    /// let mut rng = thread_rng();
+    /// assert!(ubig!(97).is_probably_prime(20, &mut rng));
+    /// assert!(!ubig!(561).is_probably_prime(20, &mut rng));
+    /// assert!(((ubig!(1) << 127) - ubig!(1)).is_probably_prime(20, &mut rng));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn is_probably_prime<R>(&self, rounds: usize, rng: &mut R) -> bool
+    where
+        R: Rng + ?Sized,
+    {
+        if let Some(result) = self.trial_division() {
+            return result;
+        }
+        let test = MillerRabin::new(self);
+        if *self < DETERMINISTIC_BOUND {
+            return test.passes_deterministic();
+        }
+        // Witnesses in range [2, n-2].
+        let range = self - UBig::from_word(3);
+        (0..rounds).all(|_| test.passes(&(UBig::random_below(&range, rng) + UBig::from_word(2))))
+    }
+
+    /// The smallest prime strictly greater than `self`.
+    ///
+    /// Candidates divisible by small primes are skipped, the rest go through the
+    /// Miller-Rabin test with a fixed set of witnesses. Below 3317044064679887385961981 the
+    /// result is definitely prime; above that it is a strong probable prime to the first 13
+    /// prime bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0).next_prime(), ubig!(2));
+    /// assert_eq!(ubig!(13).next_prime(), ubig!(17));
+    /// assert_eq!(ubig!(_1000000000000000000000).next_prime(), ubig!(_1000000000000000000117));
+    /// ```
+    pub fn next_prime(&self) -> UBig {
+        let mut candidate = self + UBig::from_word(1);
+        while candidate < TRIAL_DIVISION_BOUND {
+            if candidate.trial_division() == Some(true) {
+                return candidate;
+            }
+            candidate += UBig::from_word(1);
+        }
+        if !candidate.bit(0) {
+            candidate += UBig::from_word(1);
+        }
+        // Remainders of candidate modulo SMALL_PRIMES, updated as we step by 2.
+        let mut residues = [0u32; SMALL_PRIMES.len()];
+        for (r, &p) in residues.iter_mut().zip(SMALL_PRIMES.iter()) {
+            *r = &candidate % p;
+        }
+        loop {
+            if residues.iter().all(|&r| r != 0)
+                && MillerRabin::new(&candidate).passes_deterministic()
+            {
+                return candidate;
+            }
+            candidate += UBig::from_word(2);
+            for (r, &p) in residues.iter_mut().zip(SMALL_PRIMES.iter()) {
+                *r = (*r + 2) % p;
+            }
+        }
+    }
+
+    /// Random probable prime with exactly `bits` bits.
+    ///
+    /// The most significant bit is always set. The probability that the result is composite
+    /// is at most 2^-64.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::UBig;
+    /// # use rand::thread_rng;
+    /// let p = UBig::gen_prime(64, &mut thread_rng());
+    /// assert_eq!(p.bit_len(), 64);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn gen_prime<R>(bits: usize, rng: &mut R) -> UBig
+    where
+        R: Rng + ?Sized,
+    {
+        if bits < 2 {
+            panic!("no primes with fewer than 2 bits");
+        }
+        loop {
+            let mut candidate = UBig::random_bits(bits, rng);
+            candidate.set_bit(bits - 1);
+            candidate.set_bit(0);
+            if candidate.is_probably_prime(GEN_PRIME_ROUNDS, rng) {
+                return candidate;
+            }
+        }
+    }
+
+    /// Check divisibility by small primes.
+    ///
+    /// Returns `None` if the result is unknown.
+    fn trial_division(&self) -> Option<bool> {
+        if *self < UBig::from_word(2) {
+            return Some(false);
+        }
+        for &p in SMALL_PRIMES.iter() {
+            if self % p == 0 {
+                return Some(*self == p);
+            }
+        }
+        if *self < TRIAL_DIVISION_BOUND {
+            return Some(true);
+        }
+        None
+    }
+}
+
+/// Miller-Rabin test for an odd number n > 2.
+struct MillerRabin {
+    ring: ModuloRing,
+    /// n - 1 = d * 2^s, d odd.
+    d: UBig,
+    s: usize,
+}
+
+impl MillerRabin {
+    fn new(n: &UBig) -> MillerRabin {
+        debug_assert!(*n > UBig::from_word(2) && n.bit(0));
+        let n_minus_1 = n - UBig::from_word(1);
+        let s = n_minus_1.trailing_zeros().unwrap();
+        MillerRabin {
+            ring: ModuloRing::new(n),
+            d: n_minus_1 >> s,
+            s,
+        }
+    }
+
+    /// Run the test with the fixed witnesses that are definite below `DETERMINISTIC_BOUND`.
+    fn passes_deterministic(&self) -> bool {
+        SMALL_PRIMES[..NUM_DETERMINISTIC_WITNESSES]
+            .iter()
+            .all(|&p| self.passes(&UBig::from(p)))
+    }
+
+    /// Is n a strong probable prime to base `witness`?
+    fn passes(&self, witness: &UBig) -> bool {
+        let one = self.ring.from(1u8);
+        let minus_one = -one.clone();
+        let mut x = self.ring.from(witness).pow(&self.d);
+        if x == one || x == minus_one {
+            return true;
+        }
+        for _ in 1..self.s {
+            x = &x * &x;
+            if x == minus_one {
+                return true;
+            }
+        }
+        false
+    }
+}
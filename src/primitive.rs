@@ -38,7 +38,7 @@ where
     Self: TryFrom<Word>,
     Self: TryInto<Word>,
     Self: TryInto<usize>,
-    Self: Eq,
+    Self: Ord,
     Self: Add<Output = Self>,
     Self: Div<Output = Self>,
     Self: Mul<Output = Self>,
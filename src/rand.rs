@@ -4,7 +4,9 @@ use crate::{
     arch::word::Word,
     buffer::Buffer,
     ibig::IBig,
+    math,
     ops::UnsignedAbs,
+    primitive::WORD_BITS_USIZE,
     ubig::{Repr::*, UBig},
 };
 
@@ -22,6 +24,64 @@ impl SampleUniform for IBig {
 }
 
 impl UBig {
+    /// Uniformly distributed random number in range `[0, n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// # use rand::thread_rng;
+    /// let n = ubig!(_1000000000000000000000000);
+    /// let x = UBig::random_below(&n, &mut thread_rng());
+    /// assert!(x < n);
+    /// ```
+    #[inline]
+    pub fn random_below<R>(n: &UBig, rng: &mut R) -> UBig
+    where
+        R: Rng + ?Sized,
+    {
+        if *n == UBig::from_word(0) {
+            panic!("Empty range");
+        }
+        UBig::uniform(n, rng)
+    }
+
+    /// Uniformly distributed random number in range `[0, 2^bits)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is greater than [UBig::MAX_BIT_LEN].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// # use rand::thread_rng;
+    /// let x = UBig::random_bits(100, &mut thread_rng());
+    /// assert!(x < ubig!(1) << 100);
+    /// ```
+    pub fn random_bits<R>(bits: usize, rng: &mut R) -> UBig
+    where
+        R: Rng + ?Sized,
+    {
+        if bits > UBig::MAX_BIT_LEN {
+            UBig::panic_number_too_large();
+        }
+        let len = math::ceil_div_usize(bits, WORD_BITS_USIZE);
+        let mut buffer = Buffer::allocate(len);
+        buffer.push_zeros(len);
+        rng.fill(&mut buffer[..]);
+        let top_bits = bits % WORD_BITS_USIZE;
+        if top_bits != 0 {
+            buffer[len - 1] &= math::ones_word(top_bits as u32);
+        }
+        buffer.into()
+    }
+
     /// Random UBig in range [0..range)
     #[inline]
     fn uniform<R>(range: &UBig, rng: &mut R) -> UBig
@@ -163,7 +223,7 @@ impl UniformSampler for UniformIBig {
         B2: SampleBorrow<IBig>,
     {
         let range = high.borrow() - low.borrow();
-        if range <= IBig::from(0u8) {
+        if range <= 0u8 {
             panic!("Empty range");
         }
         UniformIBig {
@@ -179,7 +239,7 @@ impl UniformSampler for UniformIBig {
         B2: SampleBorrow<IBig>,
     {
         let range = high.borrow() - low.borrow() + IBig::from(1u8);
-        if range <= IBig::from(0u8) {
+        if range <= 0u8 {
             panic!("Empty range");
         }
         UniformIBig {
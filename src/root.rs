@@ -0,0 +1,69 @@
+//! Square roots.
+
+use crate::{ibig::IBig, sign::Sign::*, ubig::UBig};
+
+impl UBig {
+    /// Square root rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(99).sqrt(), ubig!(9));
+    /// assert_eq!(ubig!(100).sqrt(), ubig!(10));
+    /// ```
+    pub fn sqrt(&self) -> UBig {
+        let bits = self.bit_len();
+        if bits <= 1 {
+            return self.clone();
+        }
+        // Newton's method starting from 2^ceil(bits/2) >= sqrt(self).
+        // The sequence decreases monotonically until it reaches floor(sqrt(self)).
+        let mut x = UBig::from_word(1) << ((bits + 1) / 2);
+        loop {
+            let y = (&x + self / &x) >> 1;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Square root rounded down, and the remainder.
+    ///
+    /// Returns `(s, r)` such that `s * s + r == self` and `r <= 2 * s`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(99).sqrt_rem(), (ubig!(9), ubig!(18)));
+    /// ```
+    pub fn sqrt_rem(&self) -> (UBig, UBig) {
+        let s = self.sqrt();
+        let r = self - &s * &s;
+        (s, r)
+    }
+}
+
+impl IBig {
+    /// Square root rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(99).sqrt(), ibig!(9));
+    /// ```
+    #[inline]
+    pub fn sqrt(&self) -> IBig {
+        match self.sign() {
+            Positive => IBig::from(self.magnitude().sqrt()),
+            Negative => panic!("square root of a negative number"),
+        }
+    }
+}
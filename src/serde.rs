@@ -1,8 +1,17 @@
-use crate::{arch::word::Word, buffer::Buffer, ibig::IBig, primitive::WORD_BITS_USIZE, ubig::UBig};
+//! Serialization and deserialization.
+//!
+//! Human-readable formats (such as JSON) use a decimal string.
+//! Other formats use a sequence of 64-bit words, least significant first,
+//! and `IBig` is a `(sign, magnitude)` pair.
+
+use crate::{
+    arch::word::Word, buffer::Buffer, ibig::IBig, primitive::WORD_BITS_USIZE, sign::Sign,
+    ubig::UBig,
+};
 use alloc::vec::Vec;
 use core::fmt::{self, Formatter};
 use serde::{
-    de::{Deserialize, Deserializer, SeqAccess, Visitor},
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
     ser::{Serialize, SerializeSeq, Serializer},
 };
 use static_assertions::const_assert;
@@ -11,8 +20,18 @@ const_assert!(64 % WORD_BITS_USIZE == 0);
 const WORDS_PER_U64: usize = 64 / WORD_BITS_USIZE;
 
 impl Serialize for UBig {
-    #[allow(clippy::useless_conversion)]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            self.serialize_words(serializer)
+        }
+    }
+}
+
+impl UBig {
+    #[allow(clippy::useless_conversion)]
+    fn serialize_words<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let chunks = self.as_words().chunks(WORDS_PER_U64);
         let mut seq = serializer.serialize_seq(Some(chunks.len()))?;
         for chunk in chunks {
@@ -28,7 +47,11 @@ impl Serialize for UBig {
 
 impl<'de> Deserialize<'de> for UBig {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        deserializer.deserialize_seq(UBigVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(UBigVisitor)
+        } else {
+            deserializer.deserialize_seq(UBigVisitor)
+        }
     }
 }
 
@@ -38,7 +61,15 @@ impl<'de> Visitor<'de> for UBigVisitor {
     type Value = UBig;
 
     fn expecting(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "a sequence of 64-bit words")
+        write!(f, "a decimal string or a sequence of 64-bit words")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<UBig, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<UBig, E> {
+        Ok(UBig::from(v))
     }
 
     fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<UBig, A::Error> {
@@ -94,13 +125,53 @@ fn len_64_to_max_len(len_64: usize) -> usize {
 
 impl Serialize for IBig {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        (self.sign(), self.magnitude()).serialize(serializer)
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            (self.sign(), self.magnitude()).serialize(serializer)
+        }
     }
 }
 
 impl<'de> Deserialize<'de> for IBig {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let (sign, magnitude) = Deserialize::deserialize(deserializer)?;
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(IBigVisitor)
+        } else {
+            let (sign, magnitude) = Deserialize::deserialize(deserializer)?;
+            Ok(IBig::from_sign_magnitude(sign, magnitude))
+        }
+    }
+}
+
+struct IBigVisitor;
+
+impl<'de> Visitor<'de> for IBigVisitor {
+    type Value = IBig;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a decimal string or a (sign, magnitude) pair")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<IBig, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<IBig, E> {
+        Ok(IBig::from(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<IBig, E> {
+        Ok(IBig::from(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<IBig, A::Error> {
+        let sign: Sign = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let magnitude: UBig = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
         Ok(IBig::from_sign_magnitude(sign, magnitude))
     }
 }
@@ -7,10 +7,23 @@ use crate::{
 };
 use core::ops::Neg;
 
+/// The sign of an [IBig].
+///
+/// Zero is always [Positive](Sign::Positive).
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{ibig, Sign};
+/// assert_eq!(ibig!(-5).sign(), Sign::Negative);
+/// assert_eq!(-Sign::Negative, Sign::Positive);
+/// ```
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub(crate) enum Sign {
+pub enum Sign {
+    /// Zero or greater.
     Positive,
+    /// Less than zero.
     Negative,
 }
 
@@ -53,6 +66,32 @@ impl IBig {
             Negative => IBig::from(-1i8),
         }
     }
+
+    /// Returns `true` if the number is strictly negative.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::ibig;
+    /// assert!(ibig!(-5).is_negative());
+    /// assert!(!ibig!(0).is_negative());
+    /// ```
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.sign() == Negative
+    }
+
+    /// Returns `true` if the number is strictly positive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ibig::ibig;
+    /// assert!(ibig!(5).is_positive());
+    /// assert!(!ibig!(0).is_positive());
+    /// ```
+    #[inline]
+    pub fn is_positive(&self) -> bool {
+        self.sign() == Positive && *self.magnitude() != UBig::from_word(0)
+    }
 }
 
 impl Neg for IBig {
@@ -3,6 +3,7 @@
 use self::Repr::*;
 use crate::{
     arch::{ntt, word::Word},
+    assert::assert_in_const_fn,
     buffer::Buffer,
     math,
     primitive::WORD_BITS_USIZE,
@@ -45,7 +46,7 @@ pub struct UBig(Repr);
 impl UBig {
     /// Construct from one word.
     #[inline]
-    pub(crate) fn from_word(word: Word) -> UBig {
+    pub(crate) const fn from_word(word: Word) -> UBig {
         UBig(Small(word))
     }
 
@@ -70,9 +71,21 @@ impl UBig {
         }
     }
 
-    /// Representation in Words.
+    /// Representation in [Word]s, least significant first.
+    ///
+    /// There are no leading zero words, so zero is represented by an empty slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig, WORD_BITS};
+    /// assert!(ubig!(0).as_words().is_empty());
+    /// assert_eq!(ubig!(5).as_words(), [5]);
+    /// let x = (ubig!(3) << WORD_BITS) + ubig!(5);
+    /// assert_eq!(x.as_words(), [5, 3]);
+    /// ```
     #[inline]
-    pub(crate) fn as_words(&self) -> &[Word] {
+    pub fn as_words(&self) -> &[Word] {
         match self.repr() {
             Small(0) => &[],
             Small(word) => slice::from_ref(word),
@@ -80,6 +93,80 @@ impl UBig {
         }
     }
 
+    /// Number of [Word]s of memory held.
+    ///
+    /// Numbers that fit in a single [Word] are stored inline, and their capacity is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(ubig!(5).capacity_words(), 1);
+    /// let x = ubig!(1) << 1000;
+    /// assert!(x.capacity_words() >= x.as_words().len());
+    /// ```
+    #[inline]
+    pub fn capacity_words(&self) -> usize {
+        match self.repr() {
+            Small(_) => 1,
+            Large(buffer) => buffer.capacity(),
+        }
+    }
+
+    /// Release unused memory, so that the capacity is at most that of a newly computed number
+    /// of the same length: the length plus an eighth and two words.
+    ///
+    /// Numbers always keep the capacity within a quarter of the length, but a number built with
+    /// extra capacity, for example by [UBig::with_capacity], may hold more than this.
+    ///
+    /// Does nothing for numbers that fit in a single [Word].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// let mut builder = UBig::with_capacity(100);
+    /// for _ in 0..90 {
+    ///     builder.push(7);
+    /// }
+    /// let mut x = UBig::from(builder);
+    /// x.shrink_to_fit();
+    /// assert!(x.capacity_words() <= 90 + 90 / 8 + 2);
+    /// ```
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        if let Large(buffer) = &mut self.0 {
+            buffer.shrink_to_default();
+        }
+    }
+
+    /// Construct from [Word]s, least significant first.
+    ///
+    /// Leading zero words are allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig, WORD_BITS};
+    /// assert_eq!(UBig::from_words(&[]), ubig!(0));
+    /// assert_eq!(UBig::from_words(&[5, 3, 0]), (ubig!(3) << WORD_BITS) + ubig!(5));
+    /// ```
+    pub fn from_words(words: &[Word]) -> UBig {
+        let len = words
+            .iter()
+            .rposition(|&word| word != 0)
+            .map_or(0, |pos| pos + 1);
+        match len {
+            0 => UBig::from_word(0),
+            1 => UBig::from_word(words[0]),
+            _ => {
+                let mut buffer = Buffer::allocate(len);
+                buffer.extend_from_slice(&words[..len]);
+                buffer.into()
+            }
+        }
+    }
+
     /// Maximum length in `Word`s.
     ///
     /// Ensures that the number of bits fits in `usize`, which is useful for bit count
@@ -116,6 +203,83 @@ impl UBig {
     pub(crate) fn panic_number_too_large() -> ! {
         panic!("number too large, maximum is {} bits", UBig::MAX_BIT_LEN)
     }
+
+    /// Construct from a `u64` in a const context.
+    ///
+    /// Only values that fit in a single [Word] are supported, so that no allocation is needed.
+    /// For larger constants, use [ubig!](crate::ubig) at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` does not fit in a [Word]. In a const context this is a compile error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// const K: UBig = UBig::from_u64_const(1000);
+    /// assert_eq!(K, ubig!(1000));
+    /// ```
+    #[inline]
+    #[allow(clippy::unnecessary_cast)]
+    pub const fn from_u64_const(value: u64) -> UBig {
+        assert_in_const_fn(value <= Word::MAX as u64);
+        UBig::from_word(value as Word)
+    }
+
+    /// Zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::zero(), ubig!(0));
+    /// ```
+    #[inline]
+    pub fn zero() -> UBig {
+        UBig::from_word(0)
+    }
+
+    /// One.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::one(), ubig!(1));
+    /// ```
+    #[inline]
+    pub fn one() -> UBig {
+        UBig::from_word(1)
+    }
+
+    /// Check whether the number is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert!(ubig!(0).is_zero());
+    /// assert!(!ubig!(1).is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        matches!(self.repr(), Small(0))
+    }
+
+    /// Check whether the number is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert!(ubig!(1).is_one());
+    /// assert!(!ubig!(0).is_one());
+    /// ```
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        matches!(self.repr(), Small(1))
+    }
 }
 
 impl Clone for UBig {
@@ -163,14 +327,6 @@ impl From<Buffer> for UBig {
 mod tests {
     use super::*;
 
-    /// Current capacity in Words.
-    fn capacity(x: &UBig) -> usize {
-        match x.repr() {
-            Small(_) => 1,
-            Large(large) => large.capacity(),
-        }
-    }
-
     #[test]
     fn test_buffer_to_ubig() {
         let buf = Buffer::allocate(5);
@@ -195,7 +351,7 @@ mod tests {
         buf.push(3);
         buf.push(4);
         let num: UBig = buf.into();
-        assert_eq!(capacity(&num), 7);
+        assert_eq!(num.capacity_words(), 7);
 
         let mut buf = Buffer::allocate(100);
         buf.push(1);
@@ -203,7 +359,7 @@ mod tests {
         buf.push(3);
         buf.push(4);
         let num: UBig = buf.into();
-        assert_eq!(capacity(&num), 6);
+        assert_eq!(num.capacity_words(), 6);
     }
 
     #[test]
@@ -214,7 +370,7 @@ mod tests {
         let a = gen_ubig(10);
         let b = a.clone();
         assert_eq!(a, b);
-        assert_eq!(capacity(&a), capacity(&b));
+        assert_eq!(a.capacity_words(), b.capacity_words());
     }
 
     #[test]
@@ -231,25 +387,25 @@ mod tests {
         assert_eq!(a, b);
 
         let mut a = gen_ubig(9);
-        let prev_cap = capacity(&a);
+        let prev_cap = a.capacity_words();
         a.clone_from(&num);
         // The buffer should be reused, 9 is close enough to 10.
-        assert_eq!(capacity(&a), prev_cap);
-        assert_ne!(capacity(&a), capacity(&num));
+        assert_eq!(a.capacity_words(), prev_cap);
+        assert_ne!(a.capacity_words(), num.capacity_words());
 
         let mut a = gen_ubig(2);
-        let prev_cap = capacity(&a);
+        let prev_cap = a.capacity_words();
         a.clone_from(&num);
         // The buffer should now be reallocated, it's too small.
-        assert_ne!(capacity(&a), prev_cap);
-        assert_eq!(capacity(&a), capacity(&num));
+        assert_ne!(a.capacity_words(), prev_cap);
+        assert_eq!(a.capacity_words(), num.capacity_words());
 
         let mut a = gen_ubig(100);
-        let prev_cap = capacity(&a);
+        let prev_cap = a.capacity_words();
         a.clone_from(&num);
         // The buffer should now be reallocated, it's too large.
-        assert_ne!(capacity(&a), prev_cap);
-        assert_eq!(capacity(&a), capacity(&num));
+        assert_ne!(a.capacity_words(), prev_cap);
+        assert_eq!(a.capacity_words(), num.capacity_words());
     }
 
     fn gen_ubig(num_words: u16) -> UBig {
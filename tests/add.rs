@@ -2,7 +2,7 @@ use core::{
     fmt::Debug,
     ops::{Add, AddAssign, Sub, SubAssign},
 };
-use ibig::{ibig, ubig};
+use ibig::{ibig, ops::UnsignedAbs, ubig, IBig, UBig, Word};
 
 /// Test a + b = c in various ways.
 fn test_add_sub<'a, T>(a: &'a T, b: &'a T, c: &'a T)
@@ -259,3 +259,226 @@ fn test_add_sub_ibig_primitive() {
     x -= &10;
     assert_eq!(x, ibig!(-6));
 }
+
+#[test]
+fn test_add_carry_propagation() {
+    // Covers the `Small` + `Small` overflow into `Large` for every supported word size,
+    // as well as carries running across several words.
+    for bits in &[8usize, 16, 32, 64, 128, 256, 1000] {
+        let ones = (ubig!(1) << *bits) - ubig!(1);
+        let power = ubig!(1) << *bits;
+        assert_eq!(&ones + ubig!(1), power);
+        assert_eq!(ubig!(1) + &ones, power);
+        let mut x = ones.clone();
+        x += ubig!(1);
+        assert_eq!(x, power);
+        assert_eq!(&ones + &ones, (ubig!(1) << (*bits + 1)) - ubig!(2));
+
+        let neg_ones = -IBig::from(&ones);
+        assert_eq!(&neg_ones - ibig!(1), -IBig::from(&power));
+        assert_eq!(ibig!(1) - &neg_ones, IBig::from(&power));
+    }
+}
+
+#[test]
+fn test_checked_sub_ubig() {
+    assert_eq!(ubig!(7).checked_sub(&ubig!(7)), Some(ubig!(0)));
+    assert_eq!(ubig!(7).checked_sub(&ubig!(3)), Some(ubig!(4)));
+    assert_eq!(ubig!(3).checked_sub(&ubig!(7)), None);
+
+    let large = ubig!(1) << 200;
+    assert_eq!(large.checked_sub(&large), Some(ubig!(0)));
+    assert_eq!(ubig!(3).checked_sub(&large), None);
+    assert_eq!((&large + ubig!(5)).checked_sub(&large), Some(ubig!(5)));
+    assert_eq!(
+        large.checked_sub(&ubig!(1)),
+        Some((ubig!(1) << 200) - ubig!(1))
+    );
+    assert_eq!(large.checked_sub(&(&large + ubig!(1))), None);
+    assert_eq!((ubig!(1) << 100).checked_sub(&large), None);
+}
+
+#[test]
+fn test_reduce_once() {
+    let m = ubig!(10);
+    for i in 0..20u8 {
+        let mut x = UBig::from(i);
+        x.reduce_once(&m);
+        assert_eq!(x, UBig::from(i % 10));
+    }
+    let mut x = ubig!(25);
+    x.reduce_once(&m);
+    assert_eq!(x, ubig!(15));
+
+    let m = (ubig!(1) << 200) + ubig!(7);
+    for y in [ubig!(0), ubig!(1), &m - ubig!(1), ubig!(1) << 130].iter() {
+        let mut x = y + &m;
+        x.reduce_once(&m);
+        assert_eq!(x, *y);
+        assert!(x < m);
+        let mut x = y.clone();
+        x.reduce_once(&m);
+        assert_eq!(x, *y);
+    }
+    let mut x = m.clone();
+    x.reduce_once(&m);
+    assert_eq!(x, ubig!(0));
+}
+
+#[test]
+fn test_reduce_twice() {
+    let m = ubig!(10);
+    for i in 0..30u8 {
+        let mut x = UBig::from(i);
+        x.reduce_twice(&m);
+        assert_eq!(x, UBig::from(i % 10));
+    }
+
+    let m = (ubig!(1) << 200) + ubig!(7);
+    let a = &m - ubig!(1);
+    let b = &m * ubig!(2) - ubig!(1);
+    let mut x = &a + &b;
+    x.reduce_twice(&m);
+    assert_eq!(x, (&a + &b) % &m);
+    assert!(x < m);
+}
+
+#[test]
+fn test_saturating_sub_ubig() {
+    assert_eq!(ubig!(7).saturating_sub(&ubig!(7)), ubig!(0));
+    assert_eq!(ubig!(7).saturating_sub(&ubig!(3)), ubig!(4));
+    assert_eq!(ubig!(3).saturating_sub(&ubig!(7)), ubig!(0));
+
+    let large = ubig!(1) << 200;
+    assert_eq!(large.saturating_sub(&large), ubig!(0));
+    assert_eq!(ubig!(3).saturating_sub(&large), ubig!(0));
+    assert_eq!((&large + ubig!(5)).saturating_sub(&large), ubig!(5));
+    assert_eq!(
+        large.saturating_sub(&ubig!(1)),
+        (ubig!(1) << 200) - ubig!(1)
+    );
+    assert_eq!(large.saturating_sub(&(&large + ubig!(1))), ubig!(0));
+}
+
+#[test]
+fn test_checked_add_ubig() {
+    assert_eq!(ubig!(0).checked_add(&ubig!(0)), Some(ubig!(0)));
+    assert_eq!(ubig!(3).checked_add(&ubig!(4)), Some(ubig!(7)));
+    let a = ubig!(_0xffffffffffffffffffffffffffffffff);
+    assert_eq!(
+        a.checked_add(&ubig!(1)),
+        Some(ubig!(_0x100000000000000000000000000000000))
+    );
+}
+
+#[test]
+fn test_add_sub_word_in_place() {
+    let mut a = ubig!(5);
+    a.add_word(Word::MAX);
+    assert_eq!(a, UBig::from(Word::MAX) + ubig!(5));
+    a.sub_word(6);
+    assert_eq!(a, UBig::from(Word::MAX - 1));
+
+    let mut a = (ubig!(1) << 256) - ubig!(1);
+    a.add_word(1);
+    assert_eq!(a, ubig!(1) << 256);
+    a.sub_word(1);
+    assert_eq!(a, (ubig!(1) << 256) - ubig!(1));
+
+    let mut a = ubig!(1) << 64;
+    a.sub_word(1);
+    assert_eq!(a, ubig!(_0xffffffffffffffff));
+}
+
+#[test]
+#[should_panic]
+fn test_sub_word_in_place_overflow() {
+    let mut a = ubig!(3);
+    a.sub_word(4);
+}
+
+#[test]
+fn test_checked_overflowing_sub_word() {
+    assert_eq!(ubig!(5).checked_sub_word(3), Some(ubig!(2)));
+    assert_eq!(ubig!(5).checked_sub_word(5), Some(ubig!(0)));
+    assert_eq!(ubig!(0).checked_sub_word(1), None);
+    assert_eq!(ubig!(3).checked_sub_word(4), None);
+    assert_eq!(
+        (ubig!(1) << 64).checked_sub_word(1),
+        Some(ubig!(_0xffffffffffffffff))
+    );
+    assert_eq!(
+        (ubig!(1) << 256).checked_sub_word(Word::MAX),
+        Some((ubig!(1) << 256) - UBig::from(Word::MAX))
+    );
+
+    assert_eq!(ubig!(5).overflowing_sub_word(3), (ubig!(2), false));
+    assert_eq!(ubig!(0).overflowing_sub_word(0), (ubig!(0), false));
+    assert_eq!(
+        ubig!(0).overflowing_sub_word(1),
+        (UBig::from(Word::MAX), true)
+    );
+    assert_eq!(ubig!(3).overflowing_sub_word(Word::MAX), (ubig!(4), true));
+    assert_eq!(
+        (ubig!(1) << 64).overflowing_sub_word(1),
+        (ubig!(_0xffffffffffffffff), false)
+    );
+
+    // Counting down until underflow.
+    let mut x = ubig!(10);
+    let mut steps = 0;
+    loop {
+        let (next, overflow) = x.overflowing_sub_word(3);
+        if overflow {
+            break;
+        }
+        x = next;
+        steps += 1;
+    }
+    assert_eq!((x, steps), (ubig!(1), 3));
+}
+
+#[test]
+fn test_sum() {
+    let empty: [UBig; 0] = [];
+    assert_eq!(empty.iter().sum::<UBig>(), ubig!(0));
+    assert_eq!(empty.iter().cloned().sum::<UBig>(), ubig!(0));
+    let xs = [ubig!(1), ubig!(2), ubig!(_1000000000000000000000)];
+    assert_eq!(xs.iter().sum::<UBig>(), ubig!(_1000000000000000000003));
+    assert_eq!(
+        xs.iter().cloned().sum::<UBig>(),
+        ubig!(_1000000000000000000003)
+    );
+    assert_eq!((1..=100u32).map(UBig::from).sum::<UBig>(), ubig!(5050));
+
+    let ys = [ibig!(5), ibig!(-7), -ibig!(_1000000000000000000000)];
+    assert_eq!(ys.iter().sum::<IBig>(), -ibig!(_1000000000000000000002));
+    assert_eq!(
+        ys.iter().cloned().sum::<IBig>(),
+        -ibig!(_1000000000000000000002)
+    );
+    assert_eq!((-100..=100).map(IBig::from).sum::<IBig>(), ibig!(0));
+}
+
+#[test]
+fn test_abs_diff_ubig() {
+    let large = ubig!(1) << 200;
+    let values = [
+        ubig!(0),
+        ubig!(3),
+        ubig!(7),
+        large.clone(),
+        &large + ubig!(5),
+        ubig!(1) << 100,
+    ];
+    for a in values.iter() {
+        assert_eq!(a.abs_diff(a), ubig!(0));
+        for b in values.iter() {
+            assert_eq!(a.abs_diff(b), b.abs_diff(a));
+            let expected = (IBig::from(a) - IBig::from(b)).unsigned_abs();
+            assert_eq!(a.abs_diff(b), expected);
+        }
+    }
+    assert_eq!(ubig!(3).abs_diff(&ubig!(7)), ubig!(4));
+    assert_eq!(large.abs_diff(&(&large + ubig!(5))), ubig!(5));
+}
@@ -1,7 +1,7 @@
 use ibig::{
     ibig,
     ops::{AndNot, NextPowerOfTwo},
-    ubig, IBig,
+    ubig, IBig, UBig, WORD_BITS,
 };
 
 #[test]
@@ -74,6 +74,43 @@ fn test_trailing_zeros() {
     assert_eq!(ibig!(-0xf0000).trailing_zeros(), Some(16));
 }
 
+#[test]
+fn test_count_ones_zeros() {
+    let values = [
+        0u128,
+        1,
+        0b101101,
+        u64::MAX as u128,
+        1 << 64,
+        0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+        u128::MAX,
+    ];
+    for &x in &values {
+        let a = UBig::from(x);
+        assert_eq!(a.count_ones(), x.count_ones() as usize);
+        assert_eq!(
+            a.count_zeros(),
+            (128 - x.leading_zeros() - x.count_ones()) as usize
+        );
+        assert_eq!(
+            a.trailing_zeros(),
+            if x == 0 {
+                None
+            } else {
+                Some(x.trailing_zeros() as usize)
+            }
+        );
+    }
+
+    let a = ubig!(0b1011) << 1000;
+    assert_eq!(a.count_ones(), 3);
+    assert_eq!(a.count_zeros(), 1001);
+    assert_eq!(a.trailing_zeros(), Some(1000));
+    let a = (ubig!(1) << 1000) - ubig!(1);
+    assert_eq!(a.count_ones(), 1000);
+    assert_eq!(a.count_zeros(), 0);
+}
+
 #[test]
 fn test_bit_len() {
     assert_eq!(ubig!(0).bit_len(), 0);
@@ -82,6 +119,32 @@ fn test_bit_len() {
         ubig!(_0xfffffffffffffffffffff00000000000000000000000000000000000000000000000000).bit_len(),
         284
     );
+
+    for k in 0..300 {
+        let x = ubig!(1) << k;
+        assert_eq!(x.bit_len(), k + 1);
+        assert_eq!((&x - ubig!(1)).bit_len(), k);
+        assert_eq!(IBig::from(x.clone()).bit_len(), k + 1);
+        assert_eq!((-IBig::from(x)).bit_len(), k + 1);
+    }
+    assert_eq!(ibig!(0).bit_len(), 0);
+}
+
+#[test]
+fn test_set_clear_bit_round_trip() {
+    let original = ubig!(_0x123456789abcdef0123456789abcdef);
+    for n in [0, 1, 63, 64, 65, 124, 127, 128, 200, 1000].iter().copied() {
+        let mut a = original.clone();
+        let was_set = a.bit(n);
+        a.set_bit(n);
+        assert!(a.bit(n));
+        a.clear_bit(n);
+        assert!(!a.bit(n));
+        if was_set {
+            a.set_bit(n);
+        }
+        assert_eq!(a, original);
+    }
 }
 
 #[test]
@@ -105,6 +168,24 @@ fn test_is_power_of_two() {
     );
 }
 
+#[test]
+fn test_power_of_two() {
+    assert_eq!(UBig::power_of_two(0), ubig!(1));
+    assert_eq!(UBig::power_of_two(5), ubig!(32));
+    assert_eq!(
+        UBig::power_of_two(130),
+        ubig!(_0x400000000000000000000000000000000)
+    );
+    for n in 0..300 {
+        let x = UBig::power_of_two(n);
+        assert_eq!(x.bit_len(), n + 1);
+        assert!(x.is_power_of_two());
+        assert_eq!(x, ubig!(1) << n);
+    }
+    assert_eq!(IBig::power_of_two(0), ibig!(1));
+    assert_eq!(IBig::power_of_two(100), ibig!(1) << 100);
+}
+
 #[test]
 fn test_next_power_of_two() {
     assert_eq!(ubig!(0).next_power_of_two(), ubig!(1));
@@ -398,6 +479,49 @@ fn test_xor_ibig() {
     }
 }
 
+#[test]
+fn test_bit_ops_ibig_twos_complement() {
+    // Compare with the two's complement operations on i128, for values up to two words long.
+    let values: Vec<i128> = vec![
+        0,
+        1,
+        -1,
+        5,
+        -5,
+        0x1234_5678_9abc_def0,
+        -0x1234_5678_9abc_def0,
+        i64::MAX as i128,
+        i64::MIN as i128,
+        i64::MAX as i128 + 1,
+        i64::MIN as i128 - 1,
+        u64::MAX as i128,
+        -(u64::MAX as i128),
+        1 << 64,
+        -(1 << 64),
+        0x0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f,
+        -0x0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f,
+        i128::MAX,
+        i128::MIN,
+    ];
+    for &a in &values {
+        let a_big = IBig::from(a);
+        assert_eq!(!&a_big, IBig::from(!a));
+        for &b in &values {
+            let b_big = IBig::from(b);
+            assert_eq!(&a_big & &b_big, IBig::from(a & b));
+            assert_eq!(&a_big | &b_big, IBig::from(a | b));
+            assert_eq!(&a_big ^ &b_big, IBig::from(a ^ b));
+            assert_eq!((&a_big).and_not(&b_big), IBig::from(a & !b));
+        }
+    }
+
+    assert_eq!(ibig!(-1) & ibig!(5), ibig!(5));
+    assert_eq!(ibig!(-1) | ibig!(5), ibig!(-1));
+    assert_eq!(ibig!(-1) ^ ibig!(5), ibig!(-6));
+    assert_eq!(!ibig!(0), ibig!(-1));
+    assert_eq!(!ibig!(-1), ibig!(0));
+}
+
 #[test]
 fn test_and_not_ibig() {
     for a in -20i8..=20i8 {
@@ -636,3 +760,158 @@ fn test_bit_ops_ibig_primitive() {
     assert_eq!((&ibig!(0xf0f)).and_not(&0xff), ibig!(0xf00));
     assert_eq!(ibig!(-13).and_not(-1), ibig!(0));
 }
+
+#[test]
+fn test_bit_ops_ubig_u128() {
+    let values = [
+        0u128,
+        1,
+        0xff00,
+        u64::MAX as u128,
+        1 << 64,
+        0xf0f0_f0f0_f0f0_f0f0_0f0f_0f0f_0f0f_0f0f,
+        0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+        u128::MAX,
+    ];
+    for &x in &values {
+        for &y in &values {
+            let a = UBig::from(x);
+            let b = UBig::from(y);
+            assert_eq!(&a & &b, UBig::from(x & y));
+            assert_eq!(&a | &b, UBig::from(x | y));
+            assert_eq!(&a ^ &b, UBig::from(x ^ y));
+            assert_eq!((&a).and_not(&b), UBig::from(x & !y));
+        }
+    }
+}
+
+#[test]
+fn test_bit_ops_ubig_normalize() {
+    // High words cancel out, leaving a single word.
+    let a = ubig!(_0xff00000000000000000000000000000000000000000000001234);
+    let b = ubig!(_0x0f00000000000000000000000000000000000000000000000034);
+    assert_eq!(&a & ubig!(0xffff), ubig!(0x1234));
+    assert_eq!(&a ^ &a, ubig!(0));
+    assert_eq!(&a ^ (&a - ubig!(0x1234)), ubig!(0x1234));
+    assert_eq!((&a).and_not(&a - ubig!(0x1234)), ubig!(0x1234));
+    assert_eq!((&b & &a) ^ &b, ubig!(0));
+    assert_eq!(
+        ubig!(_0x100000000000000000000000000000000) & ubig!(_0x10000000000000000),
+        ubig!(0)
+    );
+}
+
+#[test]
+fn test_power_of_two_boundaries() {
+    assert_eq!(ubig!(1).next_power_of_two(), ubig!(1));
+    for i in 0..300 {
+        let p = ubig!(1) << i;
+        assert!(p.is_power_of_two());
+        assert_eq!((&p).next_power_of_two(), p);
+        let above = &p + ubig!(1);
+        assert_eq!(above.is_power_of_two(), i == 0);
+        assert_eq!(above.next_power_of_two(), &p << 1);
+        if i >= 2 {
+            let below = &p - ubig!(1);
+            assert!(!below.is_power_of_two());
+            assert_eq!(below.next_power_of_two(), p);
+        }
+    }
+}
+
+#[test]
+fn test_rotate() {
+    let values = [
+        0u64,
+        1,
+        0b1011,
+        0x8000000000000001,
+        0x123456789abcdef0,
+        u64::MAX,
+    ];
+    for &x in values.iter() {
+        for bits in 0..200 {
+            assert_eq!(
+                UBig::from(x).rotate_left(bits, 64),
+                UBig::from(x.rotate_left(bits as u32))
+            );
+            assert_eq!(
+                UBig::from(x).rotate_right(bits, 64),
+                UBig::from(x.rotate_right(bits as u32))
+            );
+        }
+    }
+    for &x in [0u32, 1, 0x80000001, 0x12345678, u32::MAX].iter() {
+        for bits in 0..100 {
+            // Bits above the width are discarded.
+            let big = (ubig!(0xabc) << 32) | UBig::from(x);
+            assert_eq!(
+                big.rotate_left(bits, 32),
+                UBig::from(x.rotate_left(bits as u32))
+            );
+            assert_eq!(
+                big.rotate_right(bits, 32),
+                UBig::from(x.rotate_right(bits as u32))
+            );
+        }
+    }
+
+    let x = (ubig!(1) << 200) | ubig!(0b101);
+    assert_eq!(x.rotate_left(1, 201), ubig!(0b1011));
+    assert_eq!(x.rotate_right(1, 201), (ubig!(0b11) << 199) | ubig!(0b10));
+    assert_eq!(x.rotate_left(300, 1000), (&x << 300));
+    assert_eq!(x.rotate_left(1000, 1000), x);
+    assert_eq!(x.rotate_left(5, 0), ubig!(0));
+    assert_eq!(x.rotate_right(5, 0), ubig!(0));
+    assert_eq!(x.rotate_left(5, 1), ubig!(1));
+}
+
+#[test]
+fn test_low_bits() {
+    let x = ubig!(_0x123456789abcdef0123456789abcdef0123456789abcdef);
+    for n in 0..250 {
+        let expected = &x & ((ubig!(1) << n) - ubig!(1));
+        assert_eq!(x.low_bits(n), expected);
+        let mut y = x.clone();
+        y.keep_low_bits_mut(n);
+        assert_eq!(y, expected);
+    }
+    assert_eq!(x.low_bits(1000), x);
+    assert_eq!(ubig!(0b1101).low_bits(2), ubig!(0b01));
+    assert_eq!(ubig!(0b1101).low_bits(0), ubig!(0));
+    assert_eq!(ubig!(0b1101).low_bits(1000), ubig!(0b1101));
+    let mut y = ubig!(0b1101);
+    y.keep_low_bits_mut(3);
+    assert_eq!(y, ubig!(0b101));
+}
+
+#[test]
+fn test_split_at_word() {
+    let a = ubig!(_0x123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef);
+    let num_words = (a.bit_len() + WORD_BITS - 1) / WORD_BITS;
+    for n in 0..=num_words + 2 {
+        let (high, low) = a.split_at_word(n);
+        assert_eq!((&high << (n * WORD_BITS)) + &low, a);
+        assert!(low.bit_len() <= n * WORD_BITS);
+        assert_eq!(high, &a >> (n * WORD_BITS));
+    }
+    assert_eq!(ubig!(0).split_at_word(0), (ubig!(0), ubig!(0)));
+    assert_eq!(ubig!(0).split_at_word(3), (ubig!(0), ubig!(0)));
+    assert_eq!(ubig!(5).split_at_word(0), (ubig!(5), ubig!(0)));
+    assert_eq!(ubig!(5).split_at_word(1), (ubig!(0), ubig!(5)));
+    // Zero words in the middle are dropped from the low part.
+    let b = ubig!(1) << (3 * WORD_BITS);
+    assert_eq!(b.split_at_word(2), (ubig!(1) << WORD_BITS, ubig!(0)));
+}
+
+#[test]
+fn test_split_at_bit() {
+    let a = ubig!(_0x123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef);
+    for n in 0..300 {
+        let (high, low) = a.split_at_bit(n);
+        assert_eq!((&high << n) + &low, a);
+        assert!(low.bit_len() <= n);
+    }
+    assert_eq!(ubig!(0).split_at_bit(10), (ubig!(0), ubig!(0)));
+    assert_eq!(ubig!(0b110101).split_at_bit(0), (ubig!(0b110101), ubig!(0)));
+}
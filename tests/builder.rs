@@ -0,0 +1,79 @@
+use ibig::{ubig, UBig, Word, WORD_BITS};
+
+#[test]
+fn test_builder() {
+    let builder = UBig::with_capacity(0);
+    assert!(builder.is_empty());
+    assert_eq!(UBig::from(builder), ubig!(0));
+
+    let mut builder = UBig::with_capacity(4);
+    for &w in [1, 2, 3, 0, 0].iter() {
+        builder.push(w);
+    }
+    assert_eq!(builder.len(), 5);
+    assert_eq!(builder.as_words(), [1, 2, 3, 0, 0]);
+    assert_eq!(
+        UBig::from(builder),
+        (ubig!(3) << (2 * WORD_BITS)) + (ubig!(2) << WORD_BITS) + ubig!(1)
+    );
+
+    let mut builder = UBig::with_capacity(1);
+    builder.push(Word::MAX);
+    assert_eq!(UBig::from(builder), UBig::from(Word::MAX));
+}
+
+#[test]
+fn test_builder_no_reallocation() {
+    let n = 1000;
+    let mut builder = UBig::with_capacity(n);
+    let capacity = builder.capacity();
+    assert!(capacity >= n);
+    for i in 0..n {
+        builder.push(i as Word + 1);
+    }
+    assert_eq!(builder.capacity(), capacity);
+    let ptr = builder.as_words().as_ptr();
+    let x = UBig::from(builder);
+    assert_eq!(x.as_words().as_ptr(), ptr);
+    assert_eq!(x.as_words().len(), n);
+    assert_eq!(x.as_words()[n - 1], n as Word);
+}
+
+#[test]
+fn test_builder_grows() {
+    let mut builder = UBig::with_capacity(2);
+    let mut expected = ubig!(0);
+    for i in 0..100 {
+        builder.push(7);
+        expected += ubig!(7) << (i * WORD_BITS);
+    }
+    assert_eq!(UBig::from(builder), expected);
+}
+
+#[test]
+fn test_shrink_to_fit() {
+    let mut builder = UBig::with_capacity(1000);
+    for i in 0..900 {
+        builder.push(i as Word + 1);
+    }
+    let mut x = UBig::from(builder);
+    assert_eq!(x.as_words().len(), 900);
+    let capacity = x.capacity_words();
+    assert!(capacity > 900 + 900 / 8 + 2);
+    let y = x.clone();
+    x.shrink_to_fit();
+    assert!(x.capacity_words() < capacity);
+    assert!(x.capacity_words() >= 900 && x.capacity_words() <= 900 + 900 / 8 + 2);
+    assert_eq!(x, y);
+
+    // A clone already has the default capacity.
+    let mut x = y.clone();
+    let capacity = x.capacity_words();
+    x.shrink_to_fit();
+    assert_eq!(x.capacity_words(), capacity);
+
+    let mut x = ubig!(7);
+    x.shrink_to_fit();
+    assert_eq!(x.capacity_words(), 1);
+    assert_eq!(x, ubig!(7));
+}
@@ -1,5 +1,5 @@
 use core::cmp::Ordering;
-use ibig::{ibig, ubig};
+use ibig::{ibig, ops::UnsignedAbs, ubig, IBig, UBig, Word};
 
 #[test]
 fn test_cmp() {
@@ -31,3 +31,129 @@ fn test_cmp() {
     assert!(ibig!(-10) < ibig!(-5));
     assert!(ibig!(-5) > ibig!(-10));
 }
+
+#[test]
+fn test_cmp_low_word() {
+    // Equal length, differing only in the lowest word.
+    let a = ubig!(_0x123456789abcdef0123456789abcdef00000000000000001);
+    let b = ubig!(_0x123456789abcdef0123456789abcdef00000000000000002);
+    assert!(a < b);
+    assert!(-IBig::from(&a) > -IBig::from(&b));
+    assert_eq!(a.cmp(&(&b - ubig!(1))), Ordering::Equal);
+
+    // Largest one-word value against smallest two-word value.
+    let small = ubig!(0xffffffffffffffff);
+    let large = ubig!(_0x10000000000000000);
+    assert!(small < large);
+    assert!(large > small);
+    assert!(IBig::from(&small) < IBig::from(&large));
+    assert!(-IBig::from(&small) > -IBig::from(&large));
+    assert!(-IBig::from(&large) < ibig!(0));
+}
+
+#[test]
+fn test_sort() {
+    let mut v: Vec<IBig> = vec![
+        ibig!(_0x100000000000000000000),
+        ibig!(-3),
+        ibig!(0),
+        ibig!(-_0x100000000000000000000),
+        ibig!(7),
+        ibig!(-0xffffffffffffffff),
+    ];
+    v.sort();
+    assert_eq!(
+        v,
+        [
+            ibig!(-_0x100000000000000000000),
+            ibig!(-0xffffffffffffffff),
+            ibig!(-3),
+            ibig!(0),
+            ibig!(7),
+            ibig!(_0x100000000000000000000),
+        ]
+    );
+
+    let mut v: Vec<UBig> = v.into_iter().map(|x| x.unsigned_abs()).collect();
+    v.sort();
+    v.dedup();
+    assert_eq!(
+        v,
+        [
+            ubig!(0),
+            ubig!(3),
+            ubig!(7),
+            ubig!(0xffffffffffffffff),
+            ubig!(_0x100000000000000000000),
+        ]
+    );
+}
+
+#[test]
+fn test_ct_eq_cmp() {
+    let values = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(2),
+        ubig!(0xffffffffffffffff),
+        ubig!(_0x10000000000000000),
+        ubig!(_0x100000000000000020000000000000003),
+        ubig!(_0x100000000000000030000000000000002),
+        ubig!(_0x200000000000000020000000000000002),
+        ubig!(1) << 1000,
+        (ubig!(1) << 1000) + ubig!(1),
+    ];
+    for a in values.iter() {
+        for b in values.iter() {
+            assert_eq!(a.ct_eq(b), a == b);
+            assert_eq!(a.ct_cmp(b), a.cmp(b));
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::cmp_owned)]
+fn test_cmp_primitive() {
+    assert_eq!(ubig!(5).cmp_word(5), Ordering::Equal);
+    assert_eq!(ubig!(5).cmp_word(6), Ordering::Less);
+    assert_eq!(ubig!(5).cmp_word(4), Ordering::Greater);
+    assert_eq!((ubig!(1) << 200).cmp_word(Word::MAX), Ordering::Greater);
+
+    assert!(ubig!(1000) == 1000u64);
+    assert!(1000u16 == ubig!(1000));
+    assert!(ubig!(1000) > 999u32);
+    assert!(ubig!(1000) < 1001u128);
+    assert!(ubig!(0) == 0u8);
+    assert!(ubig!(0) > -1i8);
+    assert!(-1i64 < ubig!(0));
+    assert!(ubig!(5) == 5i32);
+    assert!(ubig!(5) != 6usize);
+
+    let large = ubig!(_0x10000000000000000);
+    assert!(large > u64::MAX);
+    assert!(u64::MAX < large);
+    assert!(large != u64::MAX);
+    assert!(large > i64::MAX);
+    assert!(large == 0x10000000000000000u128);
+    assert!(large < u128::MAX);
+    assert!(UBig::from(u64::MAX) == u64::MAX);
+    assert!(UBig::from(u128::MAX) == u128::MAX);
+    assert!(UBig::from(u128::MAX) > u64::MAX);
+    assert!(UBig::from(u128::MAX) + ubig!(1) > u128::MAX);
+    assert!(UBig::from(i128::MAX as u128) == i128::MAX);
+
+    assert!(ibig!(-5) == -5i32);
+    assert!(-5i64 == ibig!(-5));
+    assert!(ibig!(-5) < -4i8);
+    assert!(ibig!(-5) > -6i128);
+    assert!(ibig!(-5) < 0u8);
+    assert!(ibig!(5) == 5u8);
+    assert!(ibig!(5) > -5i8);
+    assert!(ibig!(0) == 0i32);
+    assert!(-IBig::from(large.clone()) < i64::MIN);
+    assert!(IBig::from(large.clone()) > u64::MAX);
+    assert!(IBig::from(i128::MIN) == i128::MIN);
+    assert!(IBig::from(i128::MIN) - ibig!(1) < i128::MIN);
+    assert_eq!(ibig!(-5).partial_cmp(&-5isize), Some(Ordering::Equal));
+    assert_eq!(3usize.partial_cmp(&ibig!(-5)), Some(Ordering::Greater));
+}
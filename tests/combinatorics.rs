@@ -0,0 +1,45 @@
+use ibig::{ubig, UBig};
+
+#[test]
+fn test_factorial() {
+    assert_eq!(UBig::factorial(0), ubig!(1));
+    assert_eq!(UBig::factorial(1), ubig!(1));
+    assert_eq!(UBig::factorial(2), ubig!(2));
+    assert_eq!(UBig::factorial(10), ubig!(3628800));
+    assert_eq!(UBig::factorial(20), ubig!(2432902008176640000));
+    assert_eq!(
+        UBig::factorial(30),
+        ubig!(_265252859812191058636308480000000)
+    );
+
+    let mut expected = ubig!(1);
+    for n in 1..300usize {
+        expected *= UBig::from(n);
+        assert_eq!(UBig::factorial(n), expected);
+    }
+}
+
+#[test]
+fn test_binomial() {
+    for n in 0..20usize {
+        assert_eq!(UBig::binomial(n, 0), ubig!(1));
+        assert_eq!(UBig::binomial(n, n), ubig!(1));
+        assert_eq!(UBig::binomial(n, n + 1), ubig!(0));
+        for k in 0..=n {
+            assert_eq!(
+                UBig::binomial(n, k),
+                UBig::factorial(n) / (UBig::factorial(k) * UBig::factorial(n - k))
+            );
+        }
+    }
+    assert_eq!(UBig::binomial(52, 5), ubig!(2598960));
+    assert_eq!(
+        UBig::binomial(100, 50),
+        ubig!(_100891344545564193334812497256)
+    );
+    assert_eq!(UBig::binomial(usize::MAX, 1), UBig::from(usize::MAX));
+    assert_eq!(
+        UBig::binomial(usize::MAX, usize::MAX - 1),
+        UBig::from(usize::MAX)
+    );
+}
@@ -1,5 +1,5 @@
-use ibig::{error::OutOfBoundsError, ibig, ubig, IBig, UBig};
-use std::convert::TryFrom;
+use ibig::{error::OutOfBoundsError, ibig, ubig, IBig, UBig, Word, WORD_BITS};
+use std::convert::{TryFrom, TryInto};
 
 #[test]
 fn test_from_to_le_bytes() {
@@ -23,6 +23,79 @@ fn test_from_to_be_bytes() {
     assert_eq!(UBig::from_be_bytes(&bytes).to_be_bytes(), bytes);
 }
 
+#[test]
+fn test_from_iter_words() {
+    let x: UBig = core::iter::empty::<Word>().collect();
+    assert_eq!(x, ubig!(0));
+
+    let words: [Word; 5] = [1, 2, 3, 0, 0];
+    let x: UBig = words.iter().copied().collect();
+    assert_eq!(x, UBig::from_words(&words));
+    assert_eq!(x.as_words(), [1, 2, 3]);
+
+    // Unknown length.
+    let x: UBig = (1..=100).map(|i: Word| i).filter(|i| i % 3 != 0).collect();
+    let words: Vec<Word> = (1..=100).filter(|i| i % 3 != 0).collect();
+    assert_eq!(x, UBig::from_words(&words));
+}
+
+#[test]
+fn test_from_iter_bytes() {
+    let x: UBig = std::iter::empty::<u8>().collect();
+    assert_eq!(x, ubig!(0));
+    let bytes = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 0,
+    ];
+    let x: UBig = bytes.iter().copied().collect();
+    assert_eq!(x, UBig::from_le_bytes(&bytes));
+    let x: UBig = (0..100u8).filter(|b| b % 7 != 0).collect();
+    let bytes: Vec<u8> = (0..100).filter(|b| b % 7 != 0).collect();
+    assert_eq!(x, UBig::from_le_bytes(&bytes));
+}
+
+#[test]
+fn test_from_to_signed_bytes() {
+    let empty: [u8; 0] = [];
+    assert_eq!(IBig::from_signed_bytes_le(&[]), ibig!(0));
+    assert_eq!(IBig::from_signed_bytes_be(&[]), ibig!(0));
+    assert_eq!(ibig!(0).to_signed_bytes_le(), empty);
+    assert_eq!(ibig!(0).to_signed_bytes_be(), empty);
+    assert_eq!(IBig::from_signed_bytes_le(&[0; 100]), ibig!(0));
+    assert_eq!(IBig::from_signed_bytes_le(&[0xff; 100]), ibig!(-1));
+    assert_eq!(ibig!(127).to_signed_bytes_le(), [0x7f]);
+    assert_eq!(ibig!(128).to_signed_bytes_le(), [0x80, 0]);
+    assert_eq!(ibig!(-128).to_signed_bytes_le(), [0x80]);
+    assert_eq!(ibig!(-129).to_signed_bytes_le(), [0x7f, 0xff]);
+    assert_eq!(ibig!(-0x100).to_signed_bytes_be(), [0xff, 0x00]);
+
+    let mut seed: u128 = 1;
+    for _ in 0..1000 {
+        seed = seed
+            .wrapping_mul(0x2360ed051fc65da44385df649fccf645)
+            .wrapping_add(0x5851f42d4c957f2d);
+        let x = (seed as i128) >> (seed >> 121);
+        let big = IBig::from(x);
+        assert_eq!(IBig::from_signed_bytes_le(&x.to_le_bytes()), big);
+        assert_eq!(IBig::from_signed_bytes_be(&x.to_be_bytes()), big);
+
+        let le = big.to_signed_bytes_le();
+        assert_eq!(le[..], x.to_le_bytes()[..le.len()]);
+        assert_eq!(IBig::from_signed_bytes_le(&le), big);
+        let be = big.to_signed_bytes_be();
+        assert_eq!(be[..], x.to_be_bytes()[16 - be.len()..]);
+        assert_eq!(IBig::from_signed_bytes_be(&be), big);
+        if le.len() > 1 {
+            // Shortest representation.
+            let shorter = &le[..le.len() - 1];
+            assert_ne!(IBig::from_signed_bytes_le(shorter), big);
+        }
+    }
+
+    let x = -(IBig::from(1u8) << 1000) + ibig!(12345);
+    assert_eq!(IBig::from_signed_bytes_le(&x.to_signed_bytes_le()), x);
+    assert_eq!(IBig::from_signed_bytes_be(&x.to_signed_bytes_be()), x);
+}
+
 #[test]
 fn test_ubig_from_unsigned() {
     assert_eq!(UBig::from(0xf1u8), UBig::from_be_bytes(&[0xf1]));
@@ -47,6 +120,53 @@ fn test_ubig_from_unsigned() {
     assert_eq!(UBig::from(5usize), UBig::from_be_bytes(&[5]));
 }
 
+#[test]
+fn test_from_u64_const() {
+    const ZERO: UBig = UBig::from_u64_const(0);
+    const K: UBig = UBig::from_u64_const(1000);
+    #[allow(clippy::unnecessary_cast)]
+    const MAX: UBig = UBig::from_u64_const(Word::MAX as u64);
+    assert_eq!(ZERO, ubig!(0));
+    assert_eq!(K, ubig!(1000));
+    assert_eq!(MAX, UBig::from(Word::MAX));
+    assert_eq!(&K * &K, ubig!(1000000));
+}
+
+#[test]
+fn test_ubig_from_unsigned_word_boundaries() {
+    // These values need two words when `Word` is narrower than 64 bits.
+    assert_eq!(UBig::from(0xffffu64), ubig!(0xffff));
+    assert_eq!(UBig::from(0x10000u64), ubig!(0x10000));
+    assert_eq!(UBig::from(0xffffffffu64), ubig!(0xffffffff));
+    assert_eq!(UBig::from(0x100000000u64), ubig!(0x100000000));
+    assert_eq!(UBig::from(u64::MAX), ubig!(0xffffffffffffffff));
+    assert_eq!(
+        UBig::from(u64::MAX as u128 + 1),
+        ubig!(_0x10000000000000000)
+    );
+    assert_eq!(
+        UBig::from(u128::MAX),
+        ubig!(_0xffffffffffffffffffffffffffffffff)
+    );
+    assert_eq!(UBig::from(0x100000000u64) - ubig!(1), ubig!(0xffffffff));
+    assert_eq!(UBig::from(u64::MAX) + ubig!(1), UBig::from(1u128 << 64));
+    assert_eq!(
+        u64::try_from(UBig::from(0x100000000u64)),
+        Ok(0x100000000u64)
+    );
+}
+
+#[test]
+fn test_ibig_from_signed_word_boundaries() {
+    assert_eq!(IBig::from(i64::MIN), -IBig::from(1u64 << 63));
+    assert_eq!(IBig::from(i64::MAX), IBig::from((1u64 << 63) - 1));
+    assert_eq!(IBig::from(-0x100000000i64), ibig!(-0x100000000));
+    assert_eq!(IBig::from(i128::MIN), -IBig::from(1u128 << 127));
+    assert_eq!(IBig::from(i128::MAX), IBig::from((1u128 << 127) - 1));
+    assert_eq!(i64::try_from(IBig::from(i64::MIN)), Ok(i64::MIN));
+    assert_eq!(i128::try_from(IBig::from(i128::MIN)), Ok(i128::MIN));
+}
+
 #[test]
 fn test_ubig_from_bool() {
     assert_eq!(UBig::from(false), UBig::from(0u8));
@@ -164,6 +284,60 @@ fn test_ibig_to_signed() {
     assert!(i8::try_from(IBig::from(-0x100i32)).is_err());
 }
 
+#[test]
+fn test_to_primitive_exact_bounds() {
+    assert_eq!(u8::try_from(UBig::from(u8::MAX)), Ok(u8::MAX));
+    assert_eq!(
+        u8::try_from(UBig::from(u8::MAX) + ubig!(1)),
+        Err(OutOfBoundsError)
+    );
+    assert_eq!(u32::try_from(UBig::from(u32::MAX)), Ok(u32::MAX));
+    assert_eq!(
+        u32::try_from(UBig::from(u32::MAX) + ubig!(1)),
+        Err(OutOfBoundsError)
+    );
+    assert_eq!(u64::try_from(UBig::from(u64::MAX)), Ok(u64::MAX));
+    assert_eq!(
+        u64::try_from(UBig::from(u64::MAX) + ubig!(1)),
+        Err(OutOfBoundsError)
+    );
+    assert_eq!(u128::try_from(UBig::from(u128::MAX)), Ok(u128::MAX));
+    assert_eq!(
+        u128::try_from(UBig::from(u128::MAX) + ubig!(1)),
+        Err(OutOfBoundsError)
+    );
+
+    assert_eq!(i64::try_from(IBig::from(i64::MAX)), Ok(i64::MAX));
+    assert_eq!(
+        i64::try_from(IBig::from(i64::MAX) + ibig!(1)),
+        Err(OutOfBoundsError)
+    );
+    assert_eq!(i64::try_from(IBig::from(i64::MIN)), Ok(i64::MIN));
+    assert_eq!(
+        i64::try_from(IBig::from(i64::MIN) - ibig!(1)),
+        Err(OutOfBoundsError)
+    );
+    assert_eq!(i128::try_from(IBig::from(i128::MAX)), Ok(i128::MAX));
+    assert_eq!(
+        i128::try_from(IBig::from(i128::MAX) + ibig!(1)),
+        Err(OutOfBoundsError)
+    );
+    assert_eq!(i128::try_from(IBig::from(i128::MIN)), Ok(i128::MIN));
+    assert_eq!(
+        i128::try_from(IBig::from(i128::MIN) - ibig!(1)),
+        Err(OutOfBoundsError)
+    );
+
+    assert_eq!(u64::try_from(IBig::from(u64::MAX)), Ok(u64::MAX));
+    assert_eq!(
+        u64::try_from(IBig::from(u64::MAX) + ibig!(1)),
+        Err(OutOfBoundsError)
+    );
+    assert_eq!(u64::try_from(ibig!(-1)), Err(OutOfBoundsError));
+    assert_eq!(i64::try_from(UBig::from(i64::MAX as u64)), Ok(i64::MAX));
+    assert_eq!(i64::try_from(UBig::from(1u64 << 63)), Err(OutOfBoundsError));
+}
+
 #[test]
 fn test_ubig_to_ibig() {
     assert_eq!(IBig::from(UBig::from(0u32)), IBig::from(0i32));
@@ -181,6 +355,20 @@ fn test_ibig_to_ubig() {
     );
     assert!(UBig::try_from(IBig::from(-1000i32)).is_err());
     assert!(UBig::try_from(&IBig::from(-1000i32)).is_err());
+    assert_eq!(UBig::try_from(ibig!(-1)), Err(OutOfBoundsError));
+    assert_eq!(UBig::try_from(-IBig::from(0u8)), Ok(ubig!(0)));
+
+    // Round trip through IBig, moving the magnitude without a copy.
+    let x = (ubig!(1) << 1000) + ubig!(12345);
+    let ptr = x.as_words().as_ptr();
+    let y: UBig = IBig::from(x.clone()).try_into().unwrap();
+    assert_eq!(y, x);
+    let z = UBig::try_from(IBig::from(x)).unwrap();
+    assert_eq!(z.as_words().as_ptr(), ptr);
+    assert_eq!(
+        UBig::try_from(-IBig::from((ubig!(1) << 1000) + ubig!(12345))),
+        Err(OutOfBoundsError)
+    );
 }
 
 #[test]
@@ -189,6 +377,31 @@ fn test_default() {
     assert_eq!(IBig::default(), ibig!(0));
 }
 
+#[test]
+fn test_zero_one() {
+    assert_eq!(UBig::zero(), ubig!(0));
+    assert_eq!(UBig::one(), ubig!(1));
+    assert_eq!(IBig::zero(), ibig!(0));
+    assert_eq!(IBig::one(), ibig!(1));
+
+    assert!(UBig::zero().is_zero());
+    assert!(!UBig::one().is_zero());
+    assert!(UBig::one().is_one());
+    assert!(!ubig!(_0x10000000000000001).is_one());
+    assert!(!ubig!(_0x10000000000000000).is_zero());
+    assert!(IBig::zero().is_zero());
+    assert!((-IBig::zero()).is_zero());
+    assert!(IBig::one().is_one());
+    assert!(!(-IBig::one()).is_one());
+
+    let x = ubig!(_0x123456789abcdef0123456789abcdef);
+    assert_eq!(UBig::one() * &x, x);
+    assert_eq!(UBig::zero() + &x, x);
+    let y = -IBig::from(x);
+    assert_eq!(IBig::one() * &y, y);
+    assert_eq!(IBig::zero() + &y, y);
+}
+
 #[test]
 fn test_display_out_of_bounds_error() {
     assert_eq!(OutOfBoundsError.to_string(), "number out of bounds");
@@ -330,4 +543,81 @@ fn test_to_f64() {
     assert_eq!(ibig!(-7).to_f64(), -7.0f64);
     assert!((ibig!(-0x1fffffffffffff7) << 967).to_f64() > -f64::INFINITY);
     assert!((ibig!(-0x1fffffffffffff8) << 967).to_f64() == -f64::INFINITY);
+
+    for i in 0..1024 {
+        assert_eq!((ubig!(1) << i).to_f64(), (i as f64).exp2());
+    }
+    assert_eq!((ubig!(1) << 1024).to_f64(), f64::INFINITY);
+    let max = (ubig!(0x1fffffffffffff) << 971).to_f64();
+    assert_eq!(max, f64::MAX);
+}
+
+#[test]
+fn test_ibig_try_from_f64() {
+    assert_eq!(IBig::try_from(0.0f64), Ok(ibig!(0)));
+    assert_eq!(IBig::try_from(-0.0f64), Ok(ibig!(0)));
+    assert_eq!(IBig::try_from(0.999f64), Ok(ibig!(0)));
+    assert_eq!(IBig::try_from(-0.999f64), Ok(ibig!(0)));
+    assert_eq!(IBig::try_from(1.0f64), Ok(ibig!(1)));
+    assert_eq!(IBig::try_from(2.5f64), Ok(ibig!(2)));
+    assert_eq!(IBig::try_from(-2.5f64), Ok(ibig!(-2)));
+    assert_eq!(IBig::try_from(-7.99f64), Ok(ibig!(-7)));
+    assert_eq!(
+        IBig::try_from(9007199254740993.0f64),
+        Ok(ibig!(9007199254740992))
+    );
+
+    // Subnormals.
+    assert_eq!(IBig::try_from(f64::from_bits(1)), Ok(ibig!(0)));
+    assert_eq!(IBig::try_from(-f64::from_bits(1)), Ok(ibig!(0)));
+    assert_eq!(IBig::try_from(f64::MIN_POSITIVE), Ok(ibig!(0)));
+
+    for i in 0..1024 {
+        let x = (i as f64).exp2();
+        assert_eq!(IBig::try_from(x), Ok(ibig!(1) << i));
+        assert_eq!(IBig::try_from(-x), Ok(ibig!(-1) << i));
+    }
+    assert_eq!(IBig::try_from(f64::MAX), Ok(ibig!(0x1fffffffffffff) << 971));
+    assert_eq!(
+        IBig::try_from(f64::MIN),
+        Ok(ibig!(-0x1fffffffffffff) << 971)
+    );
+    for x in [1.5e300f64, -3.25e-5, 123456789.0].iter() {
+        assert_eq!(IBig::try_from(*x).unwrap().to_f64(), x.trunc());
+    }
+
+    assert_eq!(IBig::try_from(f64::NAN), Err(OutOfBoundsError));
+    assert_eq!(IBig::try_from(f64::INFINITY), Err(OutOfBoundsError));
+    assert_eq!(IBig::try_from(f64::NEG_INFINITY), Err(OutOfBoundsError));
+}
+
+#[test]
+fn test_as_from_words() {
+    assert_eq!(WORD_BITS, Word::MAX.count_ones() as usize);
+    assert!(ubig!(0).as_words().is_empty());
+    assert_eq!(ubig!(7).as_words(), [7]);
+    assert_eq!(UBig::from_words(&[]), ubig!(0));
+    assert_eq!(UBig::from_words(&[0, 0]), ubig!(0));
+    assert_eq!(UBig::from_words(&[7, 0, 0]), ubig!(7));
+    assert_eq!(UBig::from_words(&[Word::MAX]), UBig::from(Word::MAX));
+    assert_eq!(
+        UBig::from_words(&[1, 2, 3, 0]),
+        (ubig!(3) << (2 * WORD_BITS)) + (ubig!(2) << WORD_BITS) + ubig!(1)
+    );
+
+    let x = (ubig!(3) << (2 * WORD_BITS)) + ubig!(1);
+    assert_eq!(x.as_words(), [1, 0, 3]);
+
+    for x in [
+        ubig!(0),
+        ubig!(1),
+        UBig::from(Word::MAX),
+        UBig::from(Word::MAX) + ubig!(1),
+        ubig!(_0x123456789abcdef0123456789abcdef0123456789abcdef),
+        (ubig!(1) << 1000) - ubig!(1),
+    ]
+    .iter()
+    {
+        assert_eq!(UBig::from_words(x.as_words()), *x);
+    }
 }
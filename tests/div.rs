@@ -1,7 +1,7 @@
 use ibig::{
     ibig,
-    ops::{DivEuclid, DivRem, DivRemEuclid, RemEuclid},
-    ubig, IBig,
+    ops::{DivEuclid, DivRem, DivRemEuclid, RemEuclid, UnsignedAbs},
+    ubig, IBig, UBig, Word,
 };
 
 #[test]
@@ -141,6 +141,33 @@ fn test_div_rem_ubig() {
     }
 }
 
+#[test]
+fn test_div_recovers_factor() {
+    let factors = [
+        ubig!(1),
+        ubig!(3),
+        ubig!(0xffffffff),
+        ubig!(0xffffffffffffffff),
+        ubig!(_0x10000000000000000),
+        ubig!(_0x123456789abcdef0123456789abcdef0123456789abcdef),
+        (ubig!(1) << 1000) - ubig!(1),
+        ubig!(7).pow(1500),
+        ubig!(10).pow(3000) + ubig!(17),
+    ];
+    for a in &factors {
+        for b in &factors {
+            let c: UBig = a * b;
+            assert_eq!(&c / a, *b);
+            assert_eq!(&c % a, ubig!(0));
+            assert_eq!((&c).div_rem(b), (a.clone(), ubig!(0)));
+            if *a > ubig!(1) {
+                let d = &c + a - ubig!(1);
+                assert_eq!(d.div_rem(a), (b.clone(), a - ubig!(1)));
+            }
+        }
+    }
+}
+
 #[test]
 #[should_panic]
 fn test_divide_by_0_ubig() {
@@ -227,6 +254,39 @@ fn test_div_rem_euclid_ibig() {
     }
 }
 
+#[test]
+fn test_div_rem_ibig_identity() {
+    assert_eq!(ibig!(-7) / ibig!(2), ibig!(-3));
+    assert_eq!(ibig!(-7) % ibig!(2), ibig!(-1));
+    assert_eq!(ibig!(-7).div_euclid(ibig!(2)), ibig!(-4));
+    assert_eq!(ibig!(-7).rem_euclid(ibig!(2)), ibig!(1));
+
+    let magnitudes = [
+        (ubig!(7), ubig!(2)),
+        (
+            ubig!(10).pow(100) + ubig!(12345),
+            ubig!(10).pow(40) + ubig!(3),
+        ),
+        ((ubig!(1) << 500) - ubig!(1), ubig!(0xffffffffffffffff)),
+        (ubig!(3).pow(700), (ubig!(1) << 128) + ubig!(1)),
+    ];
+    for (n, d) in &magnitudes {
+        for &(n_neg, d_neg) in &[(false, false), (false, true), (true, false), (true, true)] {
+            let n = if n_neg { -IBig::from(n) } else { IBig::from(n) };
+            let d = if d_neg { -IBig::from(d) } else { IBig::from(d) };
+
+            let (q, r) = (&n).div_rem(&d);
+            assert_eq!(&q * &d + &r, n);
+            assert!((&r).unsigned_abs() < (&d).unsigned_abs());
+            assert!(r == ibig!(0) || r.signum() == n.signum());
+
+            let (q, r) = (&n).div_rem_euclid(&d);
+            assert_eq!(&q * &d + &r, n);
+            assert!(r >= ibig!(0) && (&r).unsigned_abs() < (&d).unsigned_abs());
+        }
+    }
+}
+
 #[test]
 #[should_panic]
 fn test_divide_by_0_ibig() {
@@ -460,3 +520,119 @@ fn test_div_rem_euclid_ibig_signed() {
     assert_eq!((&ibig!(-23)).div_rem_euclid(-10), (ibig!(3), 7));
     assert_eq!((&ibig!(-23)).div_rem_euclid(&(-10)), (ibig!(3), 7));
 }
+
+#[test]
+fn test_div_rem_word_in_place() {
+    let mut a = ubig!(100);
+    assert_eq!(a.div_rem_word(7), 2);
+    assert_eq!(a, ubig!(14));
+
+    let mut a = (ubig!(1) << 200) + ubig!(5);
+    assert_eq!(a.div_rem_word(2), 1);
+    assert_eq!(a, (ubig!(1) << 199) + ubig!(2));
+    let q = &a / UBig::from(Word::MAX);
+    let r = &a % UBig::from(Word::MAX);
+    assert_eq!(UBig::from(a.div_rem_word(Word::MAX)), r);
+    assert_eq!(a, q);
+}
+
+#[test]
+#[should_panic]
+fn test_div_rem_word_in_place_by_0() {
+    let mut a = ubig!(100);
+    let _ = a.div_rem_word(0);
+}
+
+#[test]
+fn test_is_multiple_of() {
+    assert!(ubig!(0).is_multiple_of(&ubig!(0)));
+    assert!(!ubig!(5).is_multiple_of(&ubig!(0)));
+    assert!(ubig!(0).is_multiple_of(&ubig!(5)));
+    assert!(ubig!(15).is_multiple_of(&ubig!(5)));
+    assert!(!ubig!(16).is_multiple_of(&ubig!(5)));
+    assert!(ubig!(5).is_multiple_of(&ubig!(1)));
+    assert!(!ubig!(5).is_multiple_of(&(ubig!(1) << 100)));
+    assert!(ubig!(0).is_multiple_of(&(ubig!(1) << 100)));
+
+    let a = (ubig!(1) << 100) + ubig!(1);
+    let b = (ubig!(1) << 200) + ubig!(7);
+    assert!((&a * &b).is_multiple_of(&a));
+    assert!((&a * &b).is_multiple_of(&b));
+    assert!(!(&a * &b + ubig!(1)).is_multiple_of(&a));
+    assert!(!a.is_multiple_of(&b));
+    assert!((&a * ubig!(3)).is_multiple_of(&ubig!(3)));
+    assert!(!a.is_multiple_of(&ubig!(3)));
+}
+
+#[test]
+fn test_div_rem_word_matches_div_rem() {
+    let a = ubig!(_0x123456789abcdef0123456789abcdef0123456789);
+    for &w in [1, 2, 3, 10, 0x1234, Word::MAX].iter() {
+        let (q, r) = (&a).div_rem(w);
+        let mut x = a.clone();
+        assert_eq!(x.div_rem_word(w), r);
+        assert_eq!(x, q);
+        assert_eq!((q, UBig::from(r)), (&a).div_rem(UBig::from(w)));
+    }
+}
+
+#[test]
+fn test_div_exact() {
+    assert_eq!(ubig!(0).div_exact(&ubig!(7)), ubig!(0));
+    assert_eq!(ubig!(0).div_exact(&(ubig!(1) << 100)), ubig!(0));
+    assert_eq!(ubig!(42).div_exact(&ubig!(6)), ubig!(7));
+    assert_eq!(ubig!(42).div_exact_word(6), ubig!(7));
+
+    let a = (ubig!(1) << 100) + ubig!(1);
+    let b = (ubig!(1) << 200) + ubig!(7);
+    let c = ubig!(_0x123456789abcdef0123456789abcdef0) << 77;
+    for x in [&a, &b, &c, &ubig!(1), &ubig!(12)].iter() {
+        for y in [&a, &b, &c, &ubig!(1), &ubig!(12)].iter() {
+            let p = *x * *y;
+            assert_eq!(p.div_exact(y), **x);
+            assert_eq!(p.div_exact(x), **y);
+        }
+    }
+    assert_eq!((&c << 1000).div_exact(&c), ubig!(1) << 1000);
+
+    for &w in [1, 2, 3, 10, 0x1234, 0x8000, Word::MAX].iter() {
+        let p = &b * UBig::from(w);
+        assert_eq!(p.div_exact_word(w), b);
+        assert_eq!(p.div_exact(&UBig::from(w)), b);
+    }
+}
+
+#[test]
+fn test_div_exact_long() {
+    let mut x = ubig!(1);
+    let mut y = ubig!(1);
+    for i in 1..300u32 {
+        x = x * ubig!(_0xfedcba9876543210fedcba9876543211) + UBig::from(i);
+        y = y * ubig!(_0x76543210fedcba9876543210fedcba98) + UBig::from(i);
+        if i % 50 == 0 {
+            let p = &x * &y;
+            assert_eq!(p.div_exact(&x), y);
+            assert_eq!(p.div_exact(&y), x);
+            assert_eq!(p, &(&p / &x) * &x);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_div_exact_by_0() {
+    let _ = ubig!(100).div_exact(&ubig!(0));
+}
+
+#[test]
+#[should_panic]
+fn test_div_exact_word_by_0() {
+    let _ = ubig!(100).div_exact_word(0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "division is not exact")]
+fn test_div_exact_not_exact() {
+    let _ = ((ubig!(1) << 200) + ubig!(1)).div_exact(&(ubig!(1) << 100));
+}
@@ -97,6 +97,51 @@ fn test_gcd_ubig() {
     }
 }
 
+/// Euclidean algorithm, for reference.
+fn gcd_euclid(a: &UBig, b: &UBig) -> UBig {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while b != ubig!(0) {
+        let r = &a % &b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+#[test]
+fn test_gcd_ubig_large() {
+    let f = ubig!(_0xfedcba9876543210fedcba9876543210fedcba9876543211);
+    let values = [
+        ubig!(1),
+        ubig!(6),
+        (ubig!(1) << 200) - ubig!(1),
+        (ubig!(1) << 256) + ubig!(1),
+        ubig!(3).pow(300),
+        ubig!(2).pow(300) * ubig!(3).pow(100),
+        ubig!(_0x123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef),
+        f.pow(5) + ubig!(1),
+    ];
+    for a in values.iter() {
+        for b in values.iter() {
+            let expected = gcd_euclid(a, b);
+            assert_eq!(a.gcd(b), expected);
+            let (fa, fb) = (a * &f, b * &f);
+            assert_eq!(fa.gcd(&fb), &expected * &f);
+            assert_eq!(fa.gcd(&fb), fa.extended_gcd(&fb).0);
+        }
+    }
+
+    // Consecutive Fibonacci numbers take the most steps.
+    let (mut x, mut y) = (ubig!(1), ubig!(1));
+    for _ in 0..2000 {
+        let z = &x + &y;
+        x = y;
+        y = z;
+    }
+    assert_eq!(x.gcd(&y), ubig!(1));
+    assert_eq!((&x * ubig!(12)).gcd(&(&y * ubig!(18))), ubig!(6));
+}
+
 #[test]
 #[should_panic]
 fn test_gcd_ubig_0_0() {
@@ -143,3 +188,109 @@ fn test_gcd_ibig_0_0() {
 fn test_extended_gcd_ibig_0_0() {
     let _ = ibig!(0).extended_gcd(&ibig!(0));
 }
+
+#[test]
+fn test_gcd_structured() {
+    // Consecutive Fibonacci numbers are coprime and the worst case for Euclid's algorithm.
+    let (mut f0, mut f1) = (ubig!(0), ubig!(1));
+    for _ in 0..1000 {
+        let f2 = &f0 + &f1;
+        f0 = f1;
+        f1 = f2;
+    }
+    assert_eq!(f0.gcd(&f1), ubig!(1));
+    let (g, x, y) = f0.extended_gcd(&f1);
+    assert_eq!(g, ubig!(1));
+    assert_eq!(x * IBig::from(&f0) + y * IBig::from(&f1), ibig!(1));
+
+    // A known common factor with a power of two.
+    let common = (ubig!(3).pow(200) + ubig!(2)) << 77;
+    let a = &common * ubig!(1000000007);
+    let b = &common * (ubig!(1) << 300) * ubig!(998244353);
+    assert_eq!(a.gcd(&b), common);
+    let (g, x, y) = a.extended_gcd(&b);
+    assert_eq!(g, common);
+    assert_eq!(x * IBig::from(&a) + y * IBig::from(&b), IBig::from(&g));
+
+    let a = -IBig::from(a);
+    let b = IBig::from(b);
+    assert_eq!(a.gcd(&b), IBig::from(&common));
+    let (g, x, y) = a.extended_gcd(&b);
+    assert_eq!(&x * &a + &y * &b, g);
+}
+
+#[test]
+fn test_mod_inverse() {
+    assert_eq!(ubig!(7).mod_inverse(&ubig!(10)), Some(ubig!(3)));
+    assert_eq!(ubig!(17).mod_inverse(&ubig!(10)), Some(ubig!(3)));
+    assert_eq!(ubig!(1).mod_inverse(&ubig!(10)), Some(ubig!(1)));
+    assert_eq!(ubig!(9).mod_inverse(&ubig!(10)), Some(ubig!(9)));
+    assert_eq!(ubig!(2).mod_inverse(&ubig!(10)), None);
+    assert_eq!(ubig!(0).mod_inverse(&ubig!(10)), None);
+    assert_eq!(ubig!(10).mod_inverse(&ubig!(10)), None);
+    assert_eq!(ubig!(0).mod_inverse(&ubig!(1)), Some(ubig!(0)));
+    assert_eq!(ubig!(5).mod_inverse(&ubig!(1)), Some(ubig!(0)));
+    assert_eq!(ubig!(1).mod_inverse(&ubig!(2)), Some(ubig!(1)));
+
+    // RSA private exponent.
+    let p = (ubig!(1) << 127) - ubig!(1);
+    let q = (ubig!(1) << 89) - ubig!(1);
+    let phi = (&p - ubig!(1)) * (&q - ubig!(1));
+    let e = ubig!(65537);
+    let d = e.mod_inverse(&phi).unwrap();
+    assert!(d < phi);
+    assert_eq!(&e * &d % &phi, ubig!(1));
+    let n = &p * &q;
+    let m = ubig!(_123456789012345678901234567890);
+    assert_eq!(m.pow_mod(&e, &n).pow_mod(&d, &n), m);
+
+    let m = (ubig!(1) << 1000) + ubig!(1);
+    assert_eq!(ubig!(2).mod_inverse(&m), Some((ubig!(1) << 999) + ubig!(1)));
+    assert_eq!((&m - ubig!(1)).mod_inverse(&m), Some(&m - ubig!(1)));
+    assert_eq!(ubig!(3).mod_inverse(&(&m * ubig!(3))), None);
+}
+
+#[test]
+#[should_panic]
+fn test_mod_inverse_0() {
+    let _ = ubig!(3).mod_inverse(&ubig!(0));
+}
+
+#[test]
+fn test_lcm() {
+    let values = [
+        ubig!(1),
+        ubig!(6),
+        ubig!(35),
+        ubig!(_0x123456789abcdef0123456789),
+        ubig!(_0x123456789abcdef0123456789) * ubig!(12),
+        ubig!(1) << 200,
+        ubig!(3).pow(150),
+    ];
+    for a in values.iter() {
+        for b in values.iter() {
+            let (g, l) = a.gcd_lcm(b);
+            assert_eq!(g, a.gcd(b));
+            assert_eq!(l, a.lcm(b));
+            assert_eq!(&g * &l, a * b);
+            assert_eq!(&l % a, ubig!(0));
+            assert_eq!(&l % b, ubig!(0));
+
+            let (a, b) = (IBig::from(a), -IBig::from(b));
+            assert_eq!(a.lcm(&b), IBig::from(&l));
+            assert_eq!((-&a).gcd_lcm(&b), (IBig::from(&g), IBig::from(&l)));
+        }
+        assert_eq!(a.lcm(&ubig!(0)), ubig!(0));
+        assert_eq!(ubig!(0).lcm(a), ubig!(0));
+        assert_eq!(a.gcd_lcm(&ubig!(0)), (a.clone(), ubig!(0)));
+    }
+    assert_eq!(ubig!(0).lcm(&ubig!(0)), ubig!(0));
+    assert_eq!(ibig!(0).lcm(&ibig!(-5)), ibig!(0));
+    assert_eq!(ubig!(4).lcm(&ubig!(6)), ubig!(12));
+}
+
+#[test]
+#[should_panic]
+fn test_gcd_lcm_0_0() {
+    let _ = ubig!(0).gcd_lcm(&ubig!(0));
+}
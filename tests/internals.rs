@@ -0,0 +1,49 @@
+use ibig::{
+    internals::{add_with_carry, sub_with_borrow, SignedWord, Word, WORD_BITS},
+    ubig, UBig,
+};
+
+#[test]
+fn test_add_with_carry() {
+    assert_eq!(add_with_carry(0, 0, false), (0, false));
+    assert_eq!(add_with_carry(0, 0, true), (1, false));
+    assert_eq!(add_with_carry(Word::MAX, 0, true), (0, true));
+    assert_eq!(
+        add_with_carry(Word::MAX, Word::MAX, true),
+        (Word::MAX, true)
+    );
+    assert_eq!(
+        add_with_carry(Word::MAX, Word::MAX, false),
+        (Word::MAX - 1, true)
+    );
+
+    // Multi-word addition from the public primitives matches UBig addition.
+    let a = (ubig!(1) << 300) - ubig!(1);
+    let b = ubig!(_0x123456789abcdef0123456789abcdef);
+    let (a_words, b_words) = (a.as_words(), b.as_words());
+    let mut sum = Vec::new();
+    let mut carry = false;
+    for (i, &a_word) in a_words.iter().enumerate() {
+        let (word, c) = add_with_carry(a_word, b_words.get(i).copied().unwrap_or(0), carry);
+        sum.push(word);
+        carry = c;
+    }
+    sum.push(Word::from(carry));
+    assert_eq!(UBig::from_words(&sum), a + b);
+}
+
+#[test]
+fn test_sub_with_borrow() {
+    assert_eq!(sub_with_borrow(5, 3, false), (2, false));
+    assert_eq!(sub_with_borrow(5, 5, true), (Word::MAX, true));
+    assert_eq!(sub_with_borrow(0, Word::MAX, true), (0, true));
+}
+
+#[test]
+fn test_word_sizes() {
+    assert_eq!(std::mem::size_of::<Word>() * 8, WORD_BITS);
+    assert_eq!(
+        std::mem::size_of::<SignedWord>(),
+        std::mem::size_of::<Word>()
+    );
+}
@@ -0,0 +1,73 @@
+use ibig::{ubig, UBig};
+
+#[test]
+fn test_ilog2() {
+    assert_eq!(ubig!(1).ilog2(), 0);
+    assert_eq!(ubig!(2).ilog2(), 1);
+    assert_eq!(ubig!(3).ilog2(), 1);
+    assert_eq!((ubig!(1) << 1000).ilog2(), 1000);
+    assert_eq!(((ubig!(1) << 1000) - ubig!(1)).ilog2(), 999);
+}
+
+#[test]
+fn test_ilog10() {
+    assert_eq!(ubig!(1).ilog10(), 0);
+    assert_eq!(ubig!(9).ilog10(), 0);
+    assert_eq!(ubig!(10).ilog10(), 1);
+    assert_eq!(ubig!(999).ilog10(), 2);
+    assert_eq!(ubig!(1000).ilog10(), 3);
+    for i in 1..300 {
+        let p = ubig!(10).pow(i);
+        assert_eq!(p.ilog10(), i);
+        assert_eq!((&p - ubig!(1)).ilog10(), i - 1);
+        assert_eq!((&p + ubig!(1)).ilog10(), i);
+    }
+}
+
+#[test]
+fn test_ilog10_powers_of_two() {
+    // The estimate from the bit length is closest to being off around powers of two.
+    for i in 60..3000 {
+        let p = ubig!(1) << i;
+        assert_eq!(p.ilog10(), p.ilog(&ubig!(10)));
+        let q = &p - ubig!(1);
+        assert_eq!(q.ilog10(), q.ilog(&ubig!(10)));
+    }
+}
+
+#[test]
+fn test_ilog() {
+    assert_eq!(ubig!(80).ilog(&ubig!(3)), 3);
+    assert_eq!(ubig!(81).ilog(&ubig!(3)), 4);
+    assert_eq!(ubig!(5).ilog(&ubig!(100)), 0);
+    let base = ubig!(_0x123456789abcdef0123456789);
+    assert_eq!(ubig!(5).ilog(&base), 0);
+    assert_eq!((&base - ubig!(1)).ilog(&base), 0);
+    assert_eq!(base.ilog(&base), 1);
+    for i in 2..40 {
+        let p = base.pow(i);
+        assert_eq!(p.ilog(&base), i);
+        assert_eq!((&p - ubig!(1)).ilog(&base), i - 1);
+    }
+    let x = UBig::from(u64::MAX);
+    assert_eq!(x.ilog(&ubig!(2)), 63);
+    assert_eq!(x.ilog(&ubig!(7)), 22);
+}
+
+#[test]
+#[should_panic]
+fn test_ilog10_zero() {
+    let _ = ubig!(0).ilog10();
+}
+
+#[test]
+#[should_panic]
+fn test_ilog2_zero() {
+    let _ = ubig!(0).ilog2();
+}
+
+#[test]
+#[should_panic]
+fn test_ilog_base_one() {
+    let _ = ubig!(10).ilog(&ubig!(1));
+}
@@ -1,4 +1,9 @@
-use ibig::{ibig, modular::ModuloRing, ubig};
+use ibig::{
+    error::ModError,
+    ibig,
+    modular::{BarrettCtx, ModuloRing, MontgomeryCtx},
+    ubig, UBig, Word, WORD_BITS,
+};
 
 #[test]
 fn test_modulus() {
@@ -364,6 +369,56 @@ fn test_pow() {
     assert_eq!(ring.from(13).pow(&(prime - ubig!(1))), ring.from(1));
 }
 
+#[test]
+fn test_ubig_pow_mod() {
+    assert_eq!(ubig!(0).pow_mod(&ubig!(0), &ubig!(7)), ubig!(1));
+    assert_eq!(
+        ubig!(13).pow_mod(&ubig!(12837918273), &ubig!(100)),
+        ubig!(53)
+    );
+    assert_eq!(ubig!(13).pow_mod(&ubig!(5), &ubig!(1)), ubig!(0));
+    assert_eq!(ubig!(0).pow_mod(&ubig!(0), &ubig!(1)), ubig!(0));
+
+    // RSA round trip with the Mersenne primes 2^521-1 and 2^607-1.
+    let p = ubig!(2).pow(521) - ubig!(1);
+    let q = ubig!(2).pow(607) - ubig!(1);
+    let n = &p * &q;
+    let phi = (&p - ubig!(1)) * (&q - ubig!(1));
+    let e = ubig!(65537);
+    let d = ModuloRing::new(&phi).from(&e).inverse().unwrap().residue();
+    let message = ubig!(_0x48656c6c6f2c20776f726c6421) << 400;
+    let ciphertext = message.pow_mod(&e, &n);
+    assert_ne!(ciphertext, message);
+    assert_eq!(ciphertext.pow_mod(&d, &n), message);
+}
+
+#[test]
+#[should_panic]
+fn test_ubig_pow_mod_zero_modulus() {
+    let _ = ubig!(2).pow_mod(&ubig!(3), &ubig!(0));
+}
+
+#[test]
+fn test_ubig_checked_pow_mod() {
+    assert_eq!(
+        ubig!(13).checked_pow_mod(&ubig!(12837918273), &ubig!(100)),
+        Ok(ubig!(53))
+    );
+    assert_eq!(
+        ubig!(13).checked_pow_mod(&ubig!(5), &ubig!(1)),
+        Ok(ubig!(0))
+    );
+    assert_eq!(
+        ubig!(2).checked_pow_mod(&ubig!(3), &ubig!(0)),
+        Err(ModError::ZeroModulus)
+    );
+    assert_eq!(
+        ubig!(0).checked_pow_mod(&ubig!(0), &ubig!(0)),
+        Err(ModError::ZeroModulus)
+    );
+    assert_eq!(ModError::ZeroModulus.to_string(), "zero modulus");
+}
+
 #[test]
 fn test_pow_signed() {
     let ring = ModuloRing::new(&ubig!(100));
@@ -417,3 +472,150 @@ fn test_format() {
         "C9F2C9CD04674EDEA3FFFFFFF (mod C9F2C9CD04674EDEA40000000)"
     );
 }
+
+#[test]
+fn test_operation_sequence_stays_reduced() {
+    for m in [ubig!(1000003), ubig!(_1000000000000000000000000000057)].iter() {
+        let ring = ModuloRing::new(m);
+        let mut x = ring.from(7);
+        let mut expected = ubig!(7);
+        for i in 0u32..100 {
+            x = &x * &x + ring.from(i) - ring.from(3 * i + 1);
+            expected = (&expected * &expected + m + i - (ubig!(3) * i + ubig!(1)) % m) % m;
+            assert!(x.residue() < *m);
+            assert_eq!(x.residue(), expected);
+        }
+        assert_eq!(
+            x.pow(&ubig!(12345)).residue(),
+            expected.pow_mod(&ubig!(12345), m)
+        );
+    }
+}
+
+#[test]
+fn test_barrett() {
+    let moduli = [
+        ubig!(1),
+        ubig!(2),
+        ubig!(497),
+        ubig!(1000),
+        UBig::from(Word::MAX),
+        ubig!(1) << 128,
+        ubig!(_1000000000000000000000000000057),
+        (ubig!(1) << 607) - ubig!(1),
+        (ubig!(1) << 600) + ubig!(2),
+    ];
+    for m in moduli.iter() {
+        let ctx = BarrettCtx::new(m);
+        assert_eq!(ctx.modulus(), m);
+        let square = m * m;
+        let values = [
+            ubig!(0),
+            ubig!(1),
+            m - ubig!(1),
+            m.clone(),
+            m + ubig!(1),
+            m * ubig!(12345) + ubig!(7),
+            &square - ubig!(1),
+            square.clone(),
+            (ubig!(1) << (2 * m.as_words().len() * WORD_BITS)) - ubig!(1),
+            (ubig!(1) << (2 * m.as_words().len() * WORD_BITS + 5)) + ubig!(3),
+        ];
+        for x in values.iter() {
+            assert_eq!(ctx.reduce(x), x % m);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_barrett_0() {
+    let _ = BarrettCtx::new(&ubig!(0));
+}
+
+#[test]
+fn test_montgomery() {
+    assert!(MontgomeryCtx::new(&ubig!(0)).is_none());
+    assert!(MontgomeryCtx::new(&ubig!(2)).is_none());
+    assert!(MontgomeryCtx::new(&(ubig!(1) << 200)).is_none());
+
+    let moduli = [
+        ubig!(1),
+        ubig!(3),
+        ubig!(497),
+        UBig::from(Word::MAX),
+        ubig!(_1000000000000000000000000000057),
+        (ubig!(1) << 607) - ubig!(1),
+    ];
+    let values = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(2),
+        ubig!(12345),
+        ubig!(_0x123456789abcdef0123456789abcdef),
+        (ubig!(1) << 1000) + ubig!(17),
+    ];
+    for m in moduli.iter() {
+        let ctx = MontgomeryCtx::new(m).unwrap();
+        assert_eq!(ctx.modulus(), m);
+        for a in values.iter() {
+            let a_mont = ctx.to_montgomery(a);
+            assert!(a_mont < *m);
+            assert_eq!(ctx.from_montgomery(&a_mont), a % m);
+            for b in values.iter() {
+                let b_mont = ctx.to_montgomery(b);
+                let product = ctx.from_montgomery(&ctx.mont_mul(&a_mont, &b_mont));
+                assert_eq!(product, a * b % m);
+                assert_eq!(ctx.pow_mod(a, b), a.pow_mod(b, m));
+            }
+        }
+        // Exponents with long runs of zeros and ones between windows.
+        for exp in [
+            (ubig!(1) << 1000) + ubig!(1),
+            (ubig!(1) << 1000) - ubig!(1),
+            ubig!(_0xf0f0f0f0f00000000000000000000001f) << 100,
+        ]
+        .iter()
+        {
+            let base = ubig!(_0x123456789abcdef0123456789abcdef);
+            assert_eq!(ctx.pow_mod(&base, exp), base.pow_mod(exp, m));
+        }
+    }
+}
+
+#[test]
+fn test_mod_add_sub_mul() {
+    let moduli = [
+        ubig!(1),
+        ubig!(10),
+        ubig!(_0xffffffffffffffff),
+        (ubig!(1) << 200) + ubig!(7),
+    ];
+    for m in moduli.iter() {
+        let values = [
+            ubig!(0),
+            ubig!(1) % m,
+            m - ubig!(1),
+            m / ubig!(2),
+            m / ubig!(3),
+        ];
+        for a in values.iter() {
+            for b in values.iter() {
+                assert_eq!(a.mod_add(b, m), (a + b) % m);
+                assert_eq!(a.mod_sub(b, m), (a + m - b) % m);
+                assert_eq!(a.mod_mul(b, m), (a * b) % m);
+            }
+        }
+    }
+
+    // Subtraction wraps around.
+    let m = (ubig!(1) << 200) + ubig!(7);
+    assert_eq!(ubig!(3).mod_sub(&ubig!(5), &m), &m - ubig!(2));
+    assert_eq!(ubig!(0).mod_sub(&(&m - ubig!(1)), &m), ubig!(1));
+}
+
+#[test]
+#[should_panic]
+fn test_mod_mul_zero_modulus() {
+    let _ = ubig!(2).mod_mul(&ubig!(3), &ubig!(0));
+}
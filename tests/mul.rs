@@ -2,7 +2,8 @@ use core::{
     fmt::Debug,
     ops::{Mul, MulAssign},
 };
-use ibig::{ibig, ubig};
+use ibig::{ibig, ubig, IBig, UBig, Word};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 fn test_mul<'a, T>(a: &'a T, b: &'a T, c: &'a T)
 where
@@ -79,6 +80,108 @@ fn test_mul_ibig() {
     }
 }
 
+/// Deterministic pseudo-random 64-bit words.
+fn pseudo_random_words(len: usize, seed: u64) -> Vec<u64> {
+    let mut x = seed;
+    (0..len)
+        .map(|_| {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            x
+        })
+        .collect()
+}
+
+fn ubig_from_words(words: &[u64]) -> UBig {
+    let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+    UBig::from_le_bytes(&bytes)
+}
+
+/// Schoolbook multiplication on 64-bit words.
+fn naive_mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut c = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry: u128 = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let t = (x as u128) * (y as u128) + (c[i + j] as u128) + carry;
+            c[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        c[i + b.len()] = carry as u64;
+    }
+    c
+}
+
+#[test]
+fn test_mul_large_naive() {
+    for &(len_a, len_b) in &[(2000, 2000), (2000, 1999), (2000, 37), (3, 2000)] {
+        if (len_a + len_b) * 64 > UBig::MAX_BIT_LEN {
+            // The product doesn't fit on small word sizes.
+            continue;
+        }
+        let a = pseudo_random_words(len_a, 1 + len_a as u64);
+        let b = pseudo_random_words(len_b, 1000 + len_b as u64);
+        let expected = ubig_from_words(&naive_mul(&a, &b));
+        let a = ubig_from_words(&a);
+        let b = ubig_from_words(&b);
+        assert_eq!(&a * &b, expected);
+        assert_eq!(&b * &a, expected);
+
+        let a = IBig::from(a);
+        let b = IBig::from(b);
+        let expected = IBig::from(expected);
+        assert_eq!(-&a * &b, -&expected);
+        assert_eq!(&a * -&b, -&expected);
+        assert_eq!(-&a * -&b, expected);
+    }
+}
+
+#[test]
+fn test_mul_algorithm_thresholds() {
+    // Lengths around the switch points between simple, Karatsuba, Toom-3 and NTT multiplication.
+    let lens = [
+        1, 2, 3, 12, 13, 24, 25, 26, 96, 97, 192, 193, 194, 500, 1025,
+    ];
+    for &len_a in &lens {
+        for &len_b in &lens {
+            if len_a < len_b || (len_a + len_b) * 64 > UBig::MAX_BIT_LEN {
+                continue;
+            }
+            let a = pseudo_random_words(len_a, 7 + len_a as u64);
+            let b = pseudo_random_words(len_b, 11 + len_b as u64);
+            let expected = ubig_from_words(&naive_mul(&a, &b));
+            assert_eq!(ubig_from_words(&a) * ubig_from_words(&b), expected);
+        }
+    }
+
+    // Squaring a number of all ones maximizes the intermediate carries.
+    for &len in lens
+        .iter()
+        .filter(|&&len| 2 * len * 64 <= UBig::MAX_BIT_LEN)
+    {
+        let a = vec![u64::MAX; len];
+        let expected = ubig_from_words(&naive_mul(&a, &a));
+        let a = ubig_from_words(&a);
+        assert_eq!(&a * &a, expected);
+    }
+}
+
+#[test]
+fn test_random_mul_lengths() {
+    let mut rng = StdRng::seed_from_u64(4);
+    // Keep the product within the size limit on small word sizes.
+    let max_len = (UBig::MAX_BIT_LEN / 128).min(1500);
+    for _ in 0..100 {
+        let len_a = rng.gen_range(1..max_len);
+        let len_b = rng.gen_range(1..max_len);
+        let a: Vec<u64> = (0..len_a).map(|_| rng.gen()).collect();
+        let b: Vec<u64> = (0..len_b).map(|_| rng.gen()).collect();
+        let expected = ubig_from_words(&naive_mul(&a, &b));
+        assert_eq!(ubig_from_words(&a) * ubig_from_words(&b), expected);
+    }
+}
+
 #[test]
 #[allow(clippy::op_ref, clippy::erasing_op)]
 fn test_mul_ubig_primitive() {
@@ -147,3 +250,243 @@ fn test_mul_ibig_primitive() {
     x *= &-2;
     assert_eq!(x, ibig!(12));
 }
+
+#[test]
+fn test_checked_mul() {
+    assert_eq!(ubig!(0).checked_mul(&ubig!(5)), Some(ubig!(0)));
+    assert_eq!(ubig!(5).checked_mul(&ubig!(0)), Some(ubig!(0)));
+    assert_eq!(ubig!(3).checked_mul(&ubig!(4)), Some(ubig!(12)));
+    let a = ubig!(_0x123456789abcdef0123456789abcdef);
+    assert_eq!(a.checked_mul(&a), Some(&a * &a));
+}
+
+#[test]
+fn test_mul_word_in_place() {
+    let mut a = ubig!(5);
+    a.mul_word(Word::MAX);
+    assert_eq!(a, ubig!(5) * UBig::from(Word::MAX));
+    a.mul_word(0);
+    assert_eq!(a, ubig!(0));
+
+    let mut a = (ubig!(1) << 200) + ubig!(1);
+    a.mul_word(3);
+    assert_eq!(a, (ubig!(3) << 200) + ubig!(3));
+    a.mul_word(1);
+    assert_eq!(a, (ubig!(3) << 200) + ubig!(3));
+}
+
+#[test]
+fn test_product() {
+    let factorial_20: UBig = "2432902008176640000".parse().unwrap();
+    assert_eq!((1..=20u32).map(UBig::from).product::<UBig>(), factorial_20);
+    let xs: Vec<UBig> = (1..=20u32).map(UBig::from).collect();
+    assert_eq!(xs.iter().product::<UBig>(), factorial_20);
+    let empty: [UBig; 0] = [];
+    assert_eq!(empty.iter().product::<UBig>(), ubig!(1));
+
+    let ys = [ibig!(-2), ibig!(3), ibig!(-5), ibig!(7)];
+    assert_eq!(ys.iter().product::<IBig>(), ibig!(210));
+    assert_eq!(ys[..3].iter().cloned().product::<IBig>(), ibig!(30));
+    assert_eq!(ys[1..].iter().cloned().product::<IBig>(), ibig!(-105));
+    let empty: [IBig; 0] = [];
+    assert_eq!(empty.iter().product::<IBig>(), ibig!(1));
+}
+
+#[test]
+fn test_product_tree() {
+    assert_eq!(UBig::product_of_slice(&[]), ubig!(1));
+    assert_eq!(UBig::product_of_slice(&[ubig!(5)]), ubig!(5));
+    assert_eq!(
+        UBig::product_of_slice(&[ubig!(5), ubig!(0), ubig!(7)]),
+        ubig!(0)
+    );
+
+    let factors: Vec<UBig> = (1..=1000u32)
+        .map(|i| UBig::from(i) * UBig::from(i).pow(i as usize % 7) + ubig!(1))
+        .collect();
+    for len in [2, 3, 10, 100, 1000].iter() {
+        let naive = factors[..*len]
+            .iter()
+            .fold(ubig!(1), |acc, factor| acc * factor);
+        assert_eq!(UBig::product_of_slice(&factors[..*len]), naive);
+    }
+}
+
+#[test]
+fn test_mul_add() {
+    let a = ubig!(_0x123456789abcdef0123456789abcdef0123456789);
+    let b = ubig!(_0xfedcba9876543210fedcba9876543210);
+    let big = ubig!(
+        _0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
+    );
+    let ubigs = [ubig!(0), ubig!(1), ubig!(7), a, b, big];
+    for x in ubigs.iter() {
+        for y in ubigs.iter() {
+            for z in ubigs.iter() {
+                assert_eq!(x.mul_add(y, z), x * y + z);
+            }
+        }
+    }
+
+    let mut ibigs = Vec::new();
+    for x in ubigs.iter() {
+        ibigs.push(IBig::from(x));
+        ibigs.push(-IBig::from(x));
+    }
+    for x in ibigs.iter() {
+        for y in ibigs.iter() {
+            for z in ibigs.iter() {
+                assert_eq!(x.mul_add(y, z), x * y + z);
+            }
+        }
+    }
+    // Exact cancellation.
+    let x = ibig!(_0x123456789abcdef0123456789abcdef);
+    let y = ibig!(_0x1000000000000000000000000000000001);
+    assert_eq!(x.mul_add(&y, &-(&x * &y)), ibig!(0));
+    assert_eq!(x.mul_add(&y, &(-(&x * &y) + ibig!(1))), ibig!(1));
+    assert_eq!(x.mul_add(&y, &(-(&x * &y) - ibig!(1))), ibig!(-1));
+}
+
+#[test]
+fn test_eval_poly() {
+    assert_eq!(UBig::eval_poly(&[], &ubig!(5)), ubig!(0));
+    assert_eq!(UBig::eval_poly(&[ubig!(7)], &ubig!(5)), ubig!(7));
+    assert_eq!(
+        UBig::eval_poly(&[ubig!(1), ubig!(0), ubig!(0), ubig!(0)], &ubig!(5)),
+        ubig!(125)
+    );
+
+    let x = ubig!(_0x123456789abcdef0123456789);
+    let coeffs = [
+        ubig!(_0xfedcba9876543210fedcba9876543210),
+        ubig!(0),
+        ubig!(3),
+        ubig!(_0x1111111111111111111111111),
+    ];
+    let expected = &coeffs[0] * x.pow(3) + &coeffs[2] * &x + &coeffs[3];
+    assert_eq!(UBig::eval_poly(&coeffs, &x), expected);
+
+    assert_eq!(IBig::eval_poly(&[], &ibig!(-5)), ibig!(0));
+    let x = -IBig::from(x);
+    let coeffs: Vec<IBig> = [ibig!(-2), ibig!(0), ibig!(5), ibig!(-1), ibig!(100)]
+        .iter()
+        .map(|c| c * &x)
+        .collect();
+    let expected = &coeffs[0] * x.pow(4) + &coeffs[2] * x.pow(2) + &coeffs[3] * &x + &coeffs[4];
+    assert_eq!(IBig::eval_poly(&coeffs, &x), expected);
+}
+
+#[test]
+fn test_accumulate_u64_operators() {
+    let digits = "31415926535897932384626433832795028841971693993751058209749445923";
+    let mut acc = ubig!(0);
+    for c in digits.chars() {
+        acc *= 10u64;
+        acc += u64::from(c.to_digit(10).unwrap());
+    }
+    assert_eq!(acc, digits.parse::<UBig>().unwrap());
+
+    // Base 2^64 digits, which take two words on 32-bit and four on 16-bit builds.
+    let limbs = [
+        u64::MAX,
+        0x0123_4567_89ab_cdef,
+        1 << 63,
+        0xffff_0000_ffff_0000,
+    ];
+    let mut acc = ubig!(0);
+    for &limb in &limbs {
+        acc *= 1u64 << 32;
+        acc *= 1u64 << 32;
+        acc += u64::MAX;
+        acc += 1u64;
+        acc -= u64::MAX;
+        acc -= 1u64;
+        acc += limb;
+    }
+    let mut expected = ubig!(0);
+    for &limb in &limbs {
+        expected = (expected << 64) + UBig::from(limb);
+    }
+    assert_eq!(acc, expected);
+    acc *= u64::MAX;
+    assert_eq!(acc, expected * UBig::from(u64::MAX));
+
+    let mut x = ubig!(_0x10000000000000000);
+    x -= u64::MAX;
+    assert_eq!(x, ubig!(1));
+}
+
+#[test]
+#[should_panic]
+fn test_sub_assign_u64_overflow() {
+    let mut x = ubig!(_0xffffffffffffffff);
+    x -= u64::MAX;
+    x -= 1u64;
+}
+
+#[cfg(feature = "rayon")]
+fn with_thread_pool<F: FnOnce() + Send>(f: F) {
+    // The parallel code paths are only used with more than one thread.
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap()
+        .install(f);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_par_product() {
+    assert_eq!(UBig::par_product_of_slice(&[]), ubig!(1));
+    assert_eq!(UBig::par_product_of_slice(&[ubig!(5)]), ubig!(5));
+    let factors: Vec<UBig> = (0..400usize)
+        .map(|i| (ubig!(1) << (i % 200)) + ubig!(3).pow(i % 50))
+        .collect();
+    let expected = UBig::product_of_slice(&factors);
+    assert_eq!(UBig::par_product_of_slice(&factors), expected);
+    with_thread_pool(|| assert_eq!(UBig::par_product_of_slice(&factors), expected));
+
+    // A single factor above the parallel threshold.
+    let large = ubig!(1) << 20000;
+    with_thread_pool(|| {
+        assert_eq!(
+            UBig::par_product_of_slice(core::slice::from_ref(&large)),
+            large
+        );
+        assert_eq!(
+            UBig::par_product_of_slice(&[large.clone(), ubig!(1)]),
+            large
+        );
+        assert_eq!(
+            UBig::par_product_of_slice(&[ubig!(1), large.clone()]),
+            large
+        );
+    });
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_mul_parallel_karatsuba() {
+    with_thread_pool(|| {
+        // Includes lengths in the Karatsuba range, which use the parallel code path.
+        for &bits in &[128 * 64, 150 * 64 + 17, 192 * 64, 1000 * 64] {
+            if 2 * bits > UBig::MAX_BIT_LEN {
+                // The product doesn't fit on small word sizes.
+                continue;
+            }
+            let a = (ubig!(1) << bits) - ubig!(1);
+            let b = (ubig!(1) << bits) - ubig!(3);
+            let expected = (ubig!(1) << (2 * bits)) - (ubig!(1) << (bits + 2)) + ubig!(3);
+            assert_eq!(&a * &b, expected);
+            let c = ubig!(3).pow(bits / 2);
+            let d = ubig!(7).pow(bits / 3);
+            let p = ubig!(1000000007);
+            assert_eq!((&c * &d) % &p, (&c % &p) * (&d % &p) % &p);
+            assert_eq!(
+                IBig::from(c.clone()) * -IBig::from(d.clone()),
+                -IBig::from(&c * &d)
+            );
+        }
+    });
+}
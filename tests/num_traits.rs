@@ -0,0 +1,58 @@
+use core::ops::Add;
+use ibig::{ibig, ubig, IBig, UBig};
+use num_traits::{Num, One, Pow, Signed, Zero};
+
+fn sum<T: Zero + Add<Output = T> + Clone>(xs: &[T]) -> T {
+    xs.iter().cloned().fold(T::zero(), |acc, x| acc + x)
+}
+
+#[test]
+fn test_generic_sum() {
+    assert_eq!(sum::<UBig>(&[]), ubig!(0));
+    assert_eq!(
+        sum(&[ubig!(1), ubig!(2), ubig!(_1000000000000000000000)]),
+        ubig!(_1000000000000000000003)
+    );
+    assert_eq!(sum::<IBig>(&[]), ibig!(0));
+    assert_eq!(
+        sum(&[ibig!(5), ibig!(-7), ibig!(-_1000000000000000000000)]),
+        ibig!(-_1000000000000000000002)
+    );
+    assert_eq!(sum(&[3u32, 4, 5]), 12);
+}
+
+#[test]
+fn test_zero_one() {
+    assert!(<UBig as Zero>::zero().is_zero());
+    assert!(!<UBig as One>::one().is_zero());
+    assert!(<UBig as One>::one().is_one());
+    assert!(<IBig as Zero>::zero().is_zero());
+    assert!(<IBig as One>::one().is_one());
+    assert!(!One::is_one(&ibig!(-1)));
+}
+
+#[test]
+fn test_num_from_str_radix() {
+    assert_eq!(<UBig as Num>::from_str_radix("ff", 16), Ok(ubig!(255)));
+    assert_eq!(<IBig as Num>::from_str_radix("-ff", 16), Ok(ibig!(-255)));
+    assert!(<UBig as Num>::from_str_radix("-1", 10).is_err());
+}
+
+#[test]
+fn test_signed() {
+    assert_eq!(Signed::abs(&ibig!(-5)), ibig!(5));
+    assert_eq!(Signed::abs_sub(&ibig!(3), &ibig!(5)), ibig!(2));
+    assert_eq!(Signed::signum(&ibig!(-5)), ibig!(-1));
+    assert!(Signed::is_positive(&ibig!(5)));
+    assert!(Signed::is_negative(&ibig!(-5)));
+    assert!(!Signed::is_positive(&ibig!(0)));
+    assert!(!Signed::is_negative(&ibig!(0)));
+}
+
+#[test]
+fn test_pow() {
+    assert_eq!(Pow::pow(ubig!(3), 4usize), ubig!(81));
+    assert_eq!(Pow::pow(&ubig!(3), 4usize), ubig!(81));
+    assert_eq!(Pow::pow(ibig!(-3), 3usize), ibig!(-27));
+    assert_eq!(Pow::pow(&ibig!(-3), 3usize), ibig!(-27));
+}
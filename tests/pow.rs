@@ -1,4 +1,4 @@
-use ibig::{ibig, ubig};
+use ibig::{ibig, ubig, IBig, UBig};
 
 #[test]
 fn test_pow_ubig() {
@@ -37,3 +37,37 @@ fn test_pow_ibig() {
         assert_eq!(a.pow(*b), *c);
     }
 }
+
+#[test]
+fn test_pow_large() {
+    let x = ubig!(2).pow(1000);
+    assert_eq!(x.bit_len(), 1001);
+    assert_eq!(x.trailing_zeros(), Some(1000));
+    let expected: UBig = "10715086071862673209484250490600018105614048117055336074437503883703510511249361224931983788156958581275946729175531468251871452856923140435984577574698574803934567774824230985421074605062371141877954182153046474983581941267398767559165543946077062914571196477686542167660429831652624386837205668069376"
+        .parse()
+        .unwrap();
+    assert_eq!(x, expected);
+
+    assert_eq!(ubig!(10).pow(500).to_string().len(), 501);
+    assert_eq!(ubig!(3).pow(301), ubig!(3).pow(150) * ubig!(3).pow(151));
+    assert_eq!(ibig!(-2).pow(1001), -IBig::from(ubig!(2).pow(1001)));
+    assert_eq!(ibig!(-2).pow(1000), IBig::from(x));
+}
+
+#[test]
+fn test_checked_pow() {
+    assert_eq!(ubig!(0).checked_pow(0), Some(ubig!(1)));
+    assert_eq!(ubig!(0).checked_pow(usize::MAX), Some(ubig!(0)));
+    assert_eq!(ubig!(1).checked_pow(usize::MAX), Some(ubig!(1)));
+    assert_eq!(ubig!(7).checked_pow(0), Some(ubig!(1)));
+    assert_eq!(ubig!(123).checked_pow(13), Some(ubig!(123).pow(13)));
+    assert_eq!(ubig!(3).checked_pow(1000), Some(ubig!(3).pow(1000)));
+
+    assert_eq!(ubig!(2).checked_pow(UBig::MAX_BIT_LEN), None);
+    assert_eq!(ubig!(2).checked_pow(usize::MAX), None);
+    assert_eq!(ubig!(3).checked_pow(UBig::MAX_BIT_LEN), None);
+    assert_eq!(
+        ubig!(_0x123456789abcdef0123456789abcdef).checked_pow(UBig::MAX_BIT_LEN / 100),
+        None
+    );
+}
@@ -0,0 +1,110 @@
+use ibig::{ubig, UBig};
+use rand::prelude::*;
+
+#[test]
+fn test_is_probably_prime_small() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let primes: Vec<u32> = (0..10000u32)
+        .filter(|&n| n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .collect();
+    for n in 0..10000u32 {
+        assert_eq!(
+            UBig::from(n).is_probably_prime(10, &mut rng),
+            primes.binary_search(&n).is_ok(),
+            "{}",
+            n
+        );
+    }
+}
+
+#[test]
+fn test_is_probably_prime_mersenne() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for &p in [31, 61, 89, 107, 127, 521, 607, 1279].iter() {
+        let m = (ubig!(1) << p) - ubig!(1);
+        assert!(m.is_probably_prime(20, &mut rng), "2^{}-1", p);
+    }
+    for &p in [29, 67, 101, 257, 1024].iter() {
+        let m = (ubig!(1) << p) - ubig!(1);
+        assert!(!m.is_probably_prime(20, &mut rng), "2^{}-1", p);
+    }
+}
+
+#[test]
+fn test_is_probably_prime_carmichael() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let carmichael = [
+        ubig!(561),
+        ubig!(41041),
+        ubig!(825265),
+        ubig!(321197185),
+        ubig!(5394826801),
+        ubig!(232250619601),
+        ubig!(9746347772161),
+        // 3 prime factors: 1303 * 16927 * 157543
+        ubig!(3474749660383),
+        // Strong pseudoprime to bases 2..37.
+        ubig!(_318665857834031151167461),
+        // (6k+1)(12k+1)(18k+1) is a Carmichael number whenever all three factors are prime.
+        (ubig!(6) * ubig!(_1000000000000000000000000000000000000007) + ubig!(1))
+            * (ubig!(12) * ubig!(_1000000000000000000000000000000000000007) + ubig!(1))
+            * (ubig!(18) * ubig!(_1000000000000000000000000000000000000007) + ubig!(1)),
+    ];
+    for n in carmichael.iter() {
+        assert!(!n.is_probably_prime(20, &mut rng), "{}", n);
+    }
+}
+
+#[test]
+fn test_is_probably_prime_products() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let p = (ubig!(1) << 127) - ubig!(1);
+    let q = (ubig!(1) << 89) - ubig!(1);
+    assert!(!(&p * &q).is_probably_prime(20, &mut rng));
+    assert!(!(&p * &p).is_probably_prime(20, &mut rng));
+    assert!(!(&p * ubig!(97)).is_probably_prime(20, &mut rng));
+    assert!(!(&p + ubig!(1)).is_probably_prime(20, &mut rng));
+}
+
+#[test]
+fn test_next_prime() {
+    assert_eq!(ubig!(0).next_prime(), ubig!(2));
+    assert_eq!(ubig!(1).next_prime(), ubig!(2));
+    assert_eq!(ubig!(2).next_prime(), ubig!(3));
+    assert_eq!(ubig!(13).next_prime(), ubig!(17));
+    assert_eq!(ubig!(9406).next_prime(), ubig!(9413));
+    assert_eq!(ubig!(9409).next_prime(), ubig!(9413));
+    assert_eq!(ubig!(_4294967295).next_prime(), ubig!(_4294967311));
+    assert_eq!(
+        ubig!(_18446744073709551557).next_prime(),
+        ubig!(_18446744073709551629)
+    );
+    let m127 = (ubig!(1) << 127) - ubig!(1);
+    assert_eq!((&m127 - ubig!(1)).next_prime(), m127);
+
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut p = ubig!(1) << 200;
+    for _ in 0..5 {
+        let q = p.next_prime();
+        assert!(q > p);
+        assert!(q.is_probably_prime(20, &mut rng));
+        p = q;
+    }
+}
+
+#[test]
+fn test_gen_prime() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for &bits in [2, 3, 10, 64, 65, 256].iter() {
+        let p = UBig::gen_prime(bits, &mut rng);
+        assert_eq!(p.bit_len(), bits);
+        assert!(p.is_probably_prime(20, &mut rng));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_gen_prime_too_small() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let _ = UBig::gen_prime(1, &mut rng);
+}
@@ -1,4 +1,4 @@
-use ibig::{error::ParseError, ibig, ubig, IBig, UBig};
+use ibig::{error::ParseError, ibig, ubig, IBig, UBig, Word};
 
 #[test]
 fn test_ubig_format() {
@@ -62,6 +62,29 @@ fn test_ubig_format() {
     );
 }
 
+#[test]
+fn test_decimal_format() {
+    assert_eq!(ubig!(0).to_string(), "0");
+    assert_eq!(ibig!(0).to_string(), "0");
+    assert_eq!(format!("{:?}", ubig!(0)), "0");
+
+    // Chunk boundaries: inner chunks are zero-padded, the top chunk is not.
+    let mut power = ubig!(1);
+    for k in 0..100 {
+        let expected = format!("1{}", "0".repeat(k));
+        assert_eq!(power.to_string(), expected);
+        assert_eq!((-IBig::from(&power)).to_string(), format!("-{}", expected));
+
+        let nines = &power - ubig!(1);
+        let expected = "9".repeat(k);
+        if k != 0 {
+            assert_eq!(nines.to_string(), expected);
+        }
+
+        power *= ubig!(10);
+    }
+}
+
 #[test]
 fn test_ubig_in_radix() {
     assert_eq!(format!("{}", ubig!(0).in_radix(2)), "0");
@@ -147,6 +170,39 @@ fn test_ibig_format() {
     assert_eq!(format!("{:=>10}", ibig!(-123)), "======-123");
 }
 
+#[test]
+fn test_power_two_format_u128() {
+    let values = [
+        0,
+        1,
+        0xff,
+        u64::MAX as u128,
+        1 << 64,
+        0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+        u128::MAX,
+    ];
+    for &x in &values {
+        let a = UBig::from(x);
+        assert_eq!(format!("{:x}", a), format!("{:x}", x));
+        assert_eq!(format!("{:X}", a), format!("{:X}", x));
+        assert_eq!(format!("{:o}", a), format!("{:o}", x));
+        assert_eq!(format!("{:b}", a), format!("{:b}", x));
+        assert_eq!(format!("{:#x}", a), format!("{:#x}", x));
+        assert_eq!(format!("{:#X}", a), format!("{:#X}", x));
+        assert_eq!(format!("{:#o}", a), format!("{:#o}", x));
+        assert_eq!(format!("{:#b}", a), format!("{:#b}", x));
+        assert_eq!(format!("{:#040x}", a), format!("{:#040x}", x));
+
+        let b = -IBig::from(x);
+        if x != 0 {
+            assert_eq!(format!("{:x}", b), format!("-{:x}", x));
+            assert_eq!(format!("{:#X}", b), format!("-{:#X}", x));
+            assert_eq!(format!("{:#o}", b), format!("-{:#o}", x));
+            assert_eq!(format!("{:b}", b), format!("-{:b}", x));
+        }
+    }
+}
+
 #[test]
 fn test_ibig_in_radix() {
     assert_eq!(format!("{}", ibig!(0).in_radix(2)), "0");
@@ -182,15 +238,24 @@ fn test_ubig_from_str_radix() {
     );
     assert_eq!(
         UBig::from_str_radix("012", 2).unwrap_err(),
-        ParseError::InvalidDigit
+        ParseError::InvalidDigit {
+            character: '2',
+            position: 2
+        }
     );
     assert_eq!(
         UBig::from_str_radix("ffffffffffffffffffffffffffffffffffffffffffffffg", 16).unwrap_err(),
-        ParseError::InvalidDigit
+        ParseError::InvalidDigit {
+            character: 'g',
+            position: 46
+        }
     );
     assert_eq!(
         UBig::from_str_radix("-0", 2).unwrap_err(),
-        ParseError::InvalidDigit
+        ParseError::InvalidDigit {
+            character: '-',
+            position: 0
+        }
     );
     assert_eq!(UBig::from_str_radix("+0", 2).unwrap(), ubig!(0));
     assert_eq!(UBig::from_str_radix("0", 2).unwrap(), ubig!(0));
@@ -239,11 +304,17 @@ fn test_ibig_from_str_radix() {
     );
     assert_eq!(
         IBig::from_str_radix("-+5", 2).unwrap_err(),
-        ParseError::InvalidDigit
+        ParseError::InvalidDigit {
+            character: '+',
+            position: 1
+        }
     );
     assert_eq!(
         IBig::from_str_radix("-012", 2).unwrap_err(),
-        ParseError::InvalidDigit
+        ParseError::InvalidDigit {
+            character: '2',
+            position: 3
+        }
     );
     assert_eq!(IBig::from_str_radix("0", 2).unwrap(), ibig!(0));
     assert_eq!(IBig::from_str_radix("+0", 2).unwrap(), ibig!(0));
@@ -312,6 +383,124 @@ fn test_radix_round_trip() {
     );
 }
 
+#[test]
+fn test_to_str_radix() {
+    assert_eq!(ubig!(0).to_str_radix(7), "0");
+    assert_eq!(ubig!(35).to_str_radix(36), "z");
+    assert_eq!(ubig!(35).to_str_radix_uppercase(36), "Z");
+    assert_eq!(ubig!(83).to_str_radix(3), "10002");
+    assert_eq!(ibig!(0).to_str_radix(36), "0");
+    assert_eq!(ibig!(-35).to_str_radix(36), "-z");
+    assert_eq!(ibig!(-35).to_str_radix_uppercase(36), "-Z");
+
+    let x = ubig!(_0x123456789abcdef0123456789abcdef0123456789abcdef) * ubig!(0xfedcba987654321);
+    let y = -IBig::from(x.clone());
+    for radix in 2..=36 {
+        let s = x.to_str_radix(radix);
+        assert_eq!(s, x.in_radix(radix).to_string());
+        assert_eq!(UBig::from_str_radix(&s, radix).unwrap(), x);
+        let s = x.to_str_radix_uppercase(radix);
+        assert_eq!(s, s.to_uppercase());
+        assert_eq!(UBig::from_str_radix(&s, radix).unwrap(), x);
+        let s = y.to_str_radix(radix);
+        assert_eq!(IBig::from_str_radix(&s, radix).unwrap(), y);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_to_str_radix_invalid() {
+    let _ = ubig!(1).to_str_radix(37);
+}
+
+#[test]
+fn test_to_string_grouped() {
+    assert_eq!(ubig!(0).to_string_grouped(3, ','), "0");
+    assert_eq!(ubig!(123).to_string_grouped(3, ','), "123");
+    assert_eq!(ubig!(1234).to_string_grouped(3, ','), "1,234");
+    assert_eq!(ubig!(1234567890).to_string_grouped(3, ','), "1,234,567,890");
+    assert_eq!(ubig!(123456).to_string_grouped(3, ' '), "123 456");
+    assert_eq!(ubig!(12345678).to_string_grouped(4, '_'), "1234_5678");
+    assert_eq!(
+        ubig!(123).to_string_grouped(1, '\u{2009}'),
+        "1\u{2009}2\u{2009}3"
+    );
+    assert_eq!(
+        ibig!(-1234567890).to_string_grouped(3, ','),
+        "-1,234,567,890"
+    );
+    assert_eq!(ibig!(-123).to_string_grouped(3, ','), "-123");
+    assert_eq!(ibig!(0).to_string_grouped(3, ','), "0");
+
+    let x = ubig!(7).pow(1000);
+    let grouped = x.to_string_grouped(3, ',');
+    assert_eq!(grouped.replace(",", ""), x.to_string());
+    assert!(grouped.split(',').skip(1).all(|group| group.len() == 3));
+    assert!((1..=3).contains(&grouped.split(',').next().unwrap().len()));
+}
+
+#[test]
+fn test_to_scientific() {
+    assert_eq!(ubig!(0).to_scientific(1), "0e0");
+    assert_eq!(ubig!(0).to_scientific(3), "0.00e0");
+    assert_eq!(ubig!(7).to_scientific(1), "7e0");
+    assert_eq!(ubig!(7).to_scientific(3), "7.00e0");
+    assert_eq!(ubig!(123).to_scientific(5), "1.2300e2");
+    assert_eq!(ubig!(123456).to_scientific(3), "1.23e5");
+    assert_eq!(ubig!(123456).to_scientific(6), "1.23456e5");
+    assert_eq!(ubig!(123556).to_scientific(3), "1.24e5");
+
+    // Ties to even.
+    assert_eq!(ubig!(1225).to_scientific(2), "1.2e3");
+    assert_eq!(ubig!(1235).to_scientific(2), "1.2e3");
+    assert_eq!(ubig!(1245).to_scientific(2), "1.2e3");
+    assert_eq!(ubig!(12451).to_scientific(2), "1.2e4");
+    assert_eq!(ubig!(1255).to_scientific(2), "1.3e3");
+
+    // Rounding carries into the exponent.
+    assert_eq!(ubig!(9999999999).to_scientific(3), "1.00e10");
+    assert_eq!(ubig!(9996).to_scientific(3), "1.00e4");
+    assert_eq!(ubig!(95).to_scientific(1), "1e2");
+    assert_eq!(ubig!(94).to_scientific(1), "9e1");
+
+    // Powers of ten.
+    assert_eq!(ubig!(10).to_scientific(1), "1e1");
+    assert_eq!(ubig!(10).pow(100).to_scientific(1), "1e100");
+    assert_eq!(ubig!(10).pow(100).to_scientific(4), "1.000e100");
+    assert_eq!(
+        (ubig!(10).pow(100) - ubig!(1)).to_scientific(4),
+        "1.000e100"
+    );
+    assert_eq!(
+        (ubig!(10).pow(100) + ubig!(1)).to_scientific(4),
+        "1.000e100"
+    );
+
+    let x = ubig!(3).pow(1000);
+    let digits = x.to_string();
+    assert_eq!(
+        x.to_scientific(digits.len()),
+        format!("{}.{}e{}", &digits[..1], &digits[1..], digits.len() - 1)
+    );
+
+    assert_eq!(ibig!(0).to_scientific(2), "0.0e0");
+    assert_eq!(ibig!(-123456).to_scientific(3), "-1.23e5");
+    assert_eq!(ibig!(-9999).to_scientific(2), "-1.0e4");
+    assert_eq!(ibig!(5).to_scientific(2), "5.0e0");
+}
+
+#[test]
+#[should_panic]
+fn test_to_scientific_zero_digits() {
+    let _ = ubig!(1).to_scientific(0);
+}
+
+#[test]
+#[should_panic]
+fn test_to_string_grouped_zero_size() {
+    let _ = ubig!(1).to_string_grouped(0, ',');
+}
+
 #[test]
 fn test_from_str_radix_with_radix_prefix() {
     assert_eq!(UBig::from_str_with_radix_prefix("17").unwrap(), ubig!(17));
@@ -391,7 +580,10 @@ fn test_from_str_radix_with_radix_prefix() {
     );
     assert_eq!(
         UBig::from_str_with_radix_prefix("0b102").unwrap_err(),
-        ParseError::InvalidDigit
+        ParseError::InvalidDigit {
+            character: '2',
+            position: 4
+        }
     );
 
     assert_eq!(
@@ -408,14 +600,50 @@ fn test_from_str_radix_with_radix_prefix() {
     );
     assert_eq!(
         IBig::from_str_with_radix_prefix("0x1fg").unwrap_err(),
-        ParseError::InvalidDigit
+        ParseError::InvalidDigit {
+            character: 'g',
+            position: 4
+        }
     );
 }
 
 #[test]
 fn test_display_errors() {
     assert_eq!(ParseError::NoDigits.to_string(), "no digits");
-    assert_eq!(ParseError::InvalidDigit.to_string(), "invalid digit");
+    assert_eq!(
+        ParseError::InvalidDigit {
+            character: 'z',
+            position: 14
+        }
+        .to_string(),
+        "invalid digit 'z' at position 14"
+    );
+    assert_eq!(
+        ParseError::InvalidSeparator { position: 3 }.to_string(),
+        "invalid separator at position 3"
+    );
+    assert_eq!(
+        "12x".parse::<UBig>().unwrap_err().to_string(),
+        "invalid digit 'x' at position 2"
+    );
+}
+
+#[test]
+fn test_parse_error_boxed() {
+    fn parse(s: &str) -> Result<UBig, Box<dyn std::error::Error>> {
+        Ok(s.parse()?)
+    }
+    assert_eq!(parse("123").unwrap(), ubig!(123));
+    let err = parse("12x").unwrap_err();
+    assert_eq!(err.to_string(), "invalid digit 'x' at position 2");
+    assert_eq!(
+        err.downcast_ref::<ParseError>(),
+        Some(&ParseError::InvalidDigit {
+            character: 'x',
+            position: 2
+        })
+    );
+    assert!(err.source().is_none());
 }
 
 #[test]
@@ -461,4 +689,749 @@ fn test_macros() {
         format!("{}", ibig!(-ppppppppppppppppppp base 32).in_radix(32)),
         "-ppppppppppppppppppp"
     );
+
+    assert_eq!(ubig!(1_000_000), UBig::from(1000000u32));
+    assert_eq!(
+        ubig!(_1_000_000_000_000_000_000_000_000_000_000_000_000),
+        UBig::from(10u8).pow(36)
+    );
+    assert_eq!(
+        ubig!(_0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff),
+        (ubig!(1) << 144) - ubig!(1)
+    );
+    assert_eq!(ubig!(_1_000 base 10), UBig::from(1000u32));
+    assert_eq!(ubig!(zz_zz base 36), UBig::from(36u32.pow(4) - 1));
+    assert_eq!(ibig!(-1_000), IBig::from(-1000i32));
+    assert_eq!(ibig!(-_0x1_ff), IBig::from(-0x1ffi32));
+    assert_eq!(
+        ibig!(-_0b1111_0000_1111_0000_1111_0000_1111_0000_1111_0000_1111_0000_1111_0000_1111_0000_1111),
+        -IBig::from((ubig!(0xf0f0f0f0f0f0f0f0) << 4) + ubig!(0xf))
+    );
+    assert_eq!(ibig!(-1_0 base 2), IBig::from(-2i32));
+}
+
+#[test]
+fn test_to_bits_radix() {
+    let empty: [u8; 0] = [];
+    assert_eq!(ubig!(0).to_bits_radix(16), empty);
+    assert_eq!(ubig!(1).to_bits_radix(2), [1]);
+    assert_eq!(ubig!(0x1f2).to_bits_radix(16), [2, 15, 1]);
+    assert_eq!(ubig!(0b100).to_bits_radix(2), [0, 0, 1]);
+
+    let values = [
+        ubig!(12345),
+        UBig::from(Word::MAX),
+        ubig!(_0x123456789abcdef0123456789abcdef0123456789),
+        (ubig!(1) << 1000) - ubig!(1),
+        ubig!(1) << 1000,
+        ubig!(3).pow(1000),
+    ];
+    for x in values.iter() {
+        for &radix in [2, 4, 8, 16, 32].iter() {
+            assert_eq!(
+                x.to_bits_radix(radix),
+                x.to_radix_digits(radix).collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_to_bits_radix_not_power_of_two() {
+    let _ = ubig!(100).to_bits_radix(10);
+}
+
+#[test]
+fn test_to_radix_digits() {
+    assert_eq!(ubig!(0).to_radix_digits(2).count(), 0);
+    assert_eq!(ubig!(0).to_radix_digits(10).count(), 0);
+    assert_eq!(
+        ubig!(1234).to_radix_digits(10).collect::<Vec<_>>(),
+        [4, 3, 2, 1]
+    );
+    assert_eq!(
+        ubig!(0xf0e).to_radix_digits(16).collect::<Vec<_>>(),
+        [14, 0, 15]
+    );
+    assert_eq!(ubig!(35).to_radix_digits(36).collect::<Vec<_>>(), [35]);
+
+    let values = [
+        ubig!(1),
+        ubig!(10).pow(19),
+        ubig!(10).pow(40),
+        ubig!(2).pow(200) - ubig!(1),
+        ubig!(3).pow(500),
+        ubig!(_0x123456789abcdef0000000000000000000000000000000000000001),
+    ];
+    for x in &values {
+        for radix in 2..=36 {
+            let digits: Vec<u8> = x.to_radix_digits(radix).collect();
+            assert!(digits.iter().all(|&d| (d as u32) < radix));
+            assert_ne!(*digits.last().unwrap(), 0);
+            assert_eq!(digits.len(), x.to_str_radix(radix).len());
+            let mut y = ubig!(0);
+            for &d in digits.iter().rev() {
+                y = y * radix + d;
+            }
+            assert_eq!(y, *x);
+        }
+    }
+}
+
+#[test]
+fn test_from_radix_digits() {
+    assert_eq!(UBig::from_radix_digits(&[], 10), Ok(ubig!(0)));
+    assert_eq!(UBig::from_radix_digits(&[0, 0, 0], 7), Ok(ubig!(0)));
+    assert_eq!(UBig::from_radix_digits(&[4, 3, 2, 1], 10), Ok(ubig!(1234)));
+    assert_eq!(
+        UBig::from_radix_digits(&[14, 0, 15, 0], 16),
+        Ok(ubig!(0xf0e))
+    );
+    assert_eq!(UBig::from_radix_digits(&[35], 36), Ok(ubig!(35)));
+    assert_eq!(
+        UBig::from_radix_digits(&[1, 2], 2),
+        Err(ParseError::DigitOutOfRange {
+            digit: 2,
+            position: 1
+        })
+    );
+    assert_eq!(
+        UBig::from_radix_digits(&[36], 36),
+        Err(ParseError::DigitOutOfRange {
+            digit: 36,
+            position: 0
+        })
+    );
+    assert_eq!(
+        UBig::from_radix_digits(&[3, 255, 12], 10),
+        Err(ParseError::DigitOutOfRange {
+            digit: 255,
+            position: 1
+        })
+    );
+
+    // Long inputs are split into chunks.
+    for x in &[
+        ubig!(3).pow(5000) + ubig!(17),
+        ubig!(3).pow(12000) + ubig!(17),
+    ] {
+        for radix in 2..=36 {
+            let digits: Vec<u8> = x.to_radix_digits(radix).collect();
+            assert_eq!(UBig::from_radix_digits(&digits, radix).as_ref(), Ok(x));
+        }
+    }
+}
+
+#[test]
+fn test_parse_error_position() {
+    let invalid = |character, position| ParseError::InvalidDigit {
+        character,
+        position,
+    };
+    assert_eq!(UBig::from_str_radix("12a4", 10), Err(invalid('a', 2)));
+    assert_eq!(UBig::from_str_radix("+12a4", 10), Err(invalid('a', 3)));
+    assert_eq!(IBig::from_str_radix("-0012a4", 10), Err(invalid('a', 5)));
+    assert_eq!(UBig::from_str_radix("1 2", 10), Err(invalid(' ', 1)));
+    assert_eq!(UBig::from_str_radix("12é4", 16), Err(invalid('é', 2)));
+    assert_eq!(UBig::from_str_radix("1ab", 8), Err(invalid('a', 1)));
+    assert_eq!(
+        UBig::from_str_with_radix_prefix("0x12z"),
+        Err(invalid('z', 4))
+    );
+    assert_eq!(
+        IBig::from_str_with_radix_prefix("-0b1012"),
+        Err(invalid('2', 6))
+    );
+    assert_eq!("12 ".parse::<UBig>(), Err(invalid(' ', 2)));
+
+    // Long inputs go through the chunked and divide-and-conquer parsers. Keep the total
+    // within the size limit for 16-bit words.
+    let mut s = "1".repeat(5000);
+    s.push('z');
+    s.push_str(&"1".repeat(5000));
+    assert_eq!(UBig::from_str_radix(&s, 10), Err(invalid('z', 5000)));
+    assert_eq!(UBig::from_str_radix(&s, 16), Err(invalid('z', 5000)));
+
+    assert_eq!(
+        UBig::from_str_radix("ffffffffffffffffffffffffffffffffffffz", 16)
+            .unwrap_err()
+            .to_string(),
+        "invalid digit 'z' at position 36"
+    );
+}
+
+#[test]
+fn test_parse_no_digits() {
+    for s in ["", "+"].iter() {
+        assert_eq!(UBig::from_str_radix(s, 10), Err(ParseError::NoDigits));
+        assert_eq!(
+            UBig::from_str_with_radix_prefix(s),
+            Err(ParseError::NoDigits)
+        );
+        assert_eq!(s.parse::<UBig>(), Err(ParseError::NoDigits));
+    }
+    for s in ["", "+", "-"].iter() {
+        assert_eq!(IBig::from_str_radix(s, 10), Err(ParseError::NoDigits));
+        assert_eq!(
+            IBig::from_str_with_radix_prefix(s),
+            Err(ParseError::NoDigits)
+        );
+        assert_eq!(s.parse::<IBig>(), Err(ParseError::NoDigits));
+    }
+    for s in ["0x", "0b", "0o", "+0x", "+0b", "+0o"].iter() {
+        assert_eq!(
+            UBig::from_str_with_radix_prefix(s),
+            Err(ParseError::NoDigits)
+        );
+        assert_eq!(
+            IBig::from_str_with_radix_prefix(s),
+            Err(ParseError::NoDigits)
+        );
+    }
+    for s in ["-0x", "-0b", "-0o"].iter() {
+        assert_eq!(
+            IBig::from_str_with_radix_prefix(s),
+            Err(ParseError::NoDigits)
+        );
+    }
+
+    // A sign is only allowed at the start, and only for the types that accept it.
+    assert_eq!(
+        UBig::from_str_radix("-", 10),
+        Err(ParseError::InvalidDigit {
+            character: '-',
+            position: 0
+        })
+    );
+    assert_eq!(
+        IBig::from_str_radix("+-", 10),
+        Err(ParseError::InvalidDigit {
+            character: '-',
+            position: 1
+        })
+    );
+    assert_eq!(
+        UBig::from_str_with_radix_prefix("0x+"),
+        Err(ParseError::InvalidDigit {
+            character: '+',
+            position: 2
+        })
+    );
+}
+
+#[test]
+fn test_parse_zeros() {
+    for s in ["0", "0000", "+0", "+000"].iter() {
+        assert_eq!(UBig::from_str_radix(s, 10), Ok(ubig!(0)));
+        assert_eq!(UBig::from_str_radix(s, 16), Ok(ubig!(0)));
+        assert_eq!(s.parse::<UBig>(), Ok(ubig!(0)));
+    }
+    for s in ["0", "0000", "-0", "+000", "-000"].iter() {
+        assert_eq!(IBig::from_str_radix(s, 10), Ok(ibig!(0)));
+        assert_eq!(IBig::from_str_radix(s, 2), Ok(ibig!(0)));
+        assert_eq!(s.parse::<IBig>(), Ok(ibig!(0)));
+    }
+    assert_eq!(UBig::from_str_with_radix_prefix("0x000"), Ok(ubig!(0)));
+    assert_eq!(IBig::from_str_with_radix_prefix("-0b0"), Ok(ibig!(0)));
+    assert_eq!(UBig::from_str_radix(&"0".repeat(100000), 10), Ok(ubig!(0)));
+    assert_eq!(UBig::from_str_radix(&"0".repeat(100000), 32), Ok(ubig!(0)));
+}
+
+#[test]
+fn test_from_str_radix_bounded() {
+    assert_eq!(UBig::from_str_radix_bounded("0", 10, 0), Ok(ubig!(0)));
+    assert_eq!(
+        UBig::from_str_radix_bounded("1", 10, 0),
+        Err(ParseError::TooLarge)
+    );
+    assert_eq!(UBig::from_str_radix_bounded("+ff", 16, 8), Ok(ubig!(0xff)));
+    assert_eq!(
+        UBig::from_str_radix_bounded("+100", 16, 8),
+        Err(ParseError::TooLarge)
+    );
+    let zeros = "0".repeat(10000);
+    assert_eq!(
+        UBig::from_str_radix_bounded(&format!("{}255", zeros), 10, 8),
+        Ok(ubig!(255))
+    );
+    assert_eq!(
+        UBig::from_str_radix_bounded(&format!("1{}", zeros), 10, 1000),
+        Err(ParseError::TooLarge)
+    );
+    assert_eq!(
+        UBig::from_str_radix_bounded("12x", 10, 100),
+        Err(ParseError::InvalidDigit {
+            character: 'x',
+            position: 2
+        })
+    );
+    assert_eq!(
+        UBig::from_str_radix_bounded("", 10, 100),
+        Err(ParseError::NoDigits)
+    );
+
+    for bits in 1..300 {
+        let max = (ubig!(1) << bits) - ubig!(1);
+        let over = ubig!(1) << bits;
+        for &radix in [2, 3, 10, 16, 36].iter() {
+            assert_eq!(
+                UBig::from_str_radix_bounded(&max.to_str_radix(radix), radix, bits),
+                Ok(max.clone())
+            );
+            assert_eq!(
+                UBig::from_str_radix_bounded(&over.to_str_radix(radix), radix, bits),
+                Err(ParseError::TooLarge)
+            );
+        }
+    }
+    assert_eq!(ParseError::TooLarge.to_string(), "number too large");
+}
+
+#[test]
+fn test_from_str_radix_separated() {
+    assert_eq!(
+        UBig::from_str_radix_separated("1_000_000", 10),
+        Ok(ubig!(1000000))
+    );
+    assert_eq!(
+        UBig::from_str_radix_separated("1000000", 10),
+        Ok(ubig!(1000000))
+    );
+    assert_eq!(UBig::from_str_radix_separated("1_0_0", 10), Ok(ubig!(100)));
+    assert_eq!(
+        UBig::from_str_radix_separated("+ff_ff", 16),
+        Ok(ubig!(0xffff))
+    );
+    assert_eq!(UBig::from_str_radix_separated("0_0", 10), Ok(ubig!(0)));
+    assert_eq!(IBig::from_str_radix_separated("-1_0", 2), Ok(ibig!(-2)));
+    assert_eq!(
+        UBig::from_str_radix_separated(&["123456789"; 100].join("_"), 10),
+        UBig::from_str_radix(&"123456789".repeat(100), 10)
+    );
+    assert_eq!(
+        UBig::from_str_radix_separated(&["1_2345_6789"; 1000].join("_"), 10),
+        UBig::from_str_radix(&"123456789".repeat(1000), 10)
+    );
+    assert_eq!(
+        UBig::from_str_radix_separated(&["0_0_0"; 10].join("_"), 10),
+        Ok(ubig!(0))
+    );
+    assert_eq!(
+        UBig::from_str_radix_separated(&["fedc_ba98_7"; 1000].join("_"), 16),
+        UBig::from_str_radix(&"fedcba987".repeat(1000), 16)
+    );
+    assert_eq!(
+        UBig::from_str_radix("1_0", 10),
+        Err(ParseError::InvalidDigit {
+            character: '_',
+            position: 1
+        })
+    );
+    assert_eq!(
+        UBig::from_str_radix_separated("1_x", 10),
+        Err(ParseError::InvalidDigit {
+            character: 'x',
+            position: 2
+        })
+    );
+
+    let invalid_separator = |position| ParseError::InvalidSeparator { position };
+    assert_eq!(
+        UBig::from_str_radix_separated("_1", 10),
+        Err(invalid_separator(0))
+    );
+    assert_eq!(
+        UBig::from_str_radix_separated("+_1", 10),
+        Err(invalid_separator(1))
+    );
+    assert_eq!(
+        IBig::from_str_radix_separated("-_1", 10),
+        Err(invalid_separator(1))
+    );
+    assert_eq!(
+        UBig::from_str_radix_separated("1_", 10),
+        Err(invalid_separator(1))
+    );
+    assert_eq!(
+        UBig::from_str_radix_separated("1__0", 10),
+        Err(invalid_separator(2))
+    );
+    assert_eq!(
+        UBig::from_str_radix_separated("_", 10),
+        Err(invalid_separator(0))
+    );
+    assert_eq!(
+        IBig::from_str_radix_separated("-1_000_", 10),
+        Err(invalid_separator(6))
+    );
+
+    assert_eq!(
+        UBig::from_str_with_radix_prefix_separated("0xff_ff"),
+        Ok(ubig!(0xffff))
+    );
+    assert_eq!(
+        UBig::from_str_with_radix_prefix_separated("+0b1_0"),
+        Ok(ubig!(2))
+    );
+    assert_eq!(
+        IBig::from_str_with_radix_prefix_separated("-0o7_7"),
+        Ok(ibig!(-0o77))
+    );
+    assert_eq!(
+        UBig::from_str_with_radix_prefix_separated("1_000"),
+        Ok(ubig!(1000))
+    );
+    assert_eq!(
+        UBig::from_str_with_radix_prefix_separated("0x_ff"),
+        Err(invalid_separator(2))
+    );
+    assert_eq!(
+        IBig::from_str_with_radix_prefix_separated("-0xff__f"),
+        Err(invalid_separator(6))
+    );
+    assert_eq!(
+        UBig::from_str_with_radix_prefix_separated("0x"),
+        Err(ParseError::NoDigits)
+    );
+
+    assert_eq!(
+        UBig::from_str_radix_separated("", 10),
+        Err(ParseError::NoDigits)
+    );
+    assert_eq!(
+        IBig::from_str_radix_separated("-", 10),
+        Err(ParseError::NoDigits)
+    );
+    assert_eq!(
+        UBig::from_str_radix_separated("1_00z_0", 10),
+        Err(ParseError::InvalidDigit {
+            character: 'z',
+            position: 4
+        })
+    );
+    // Separators are not accepted by the other parsers.
+    assert_eq!(
+        UBig::from_str_radix("1_000", 10),
+        Err(ParseError::InvalidDigit {
+            character: '_',
+            position: 1
+        })
+    );
+    assert_eq!(
+        ParseError::InvalidSeparator { position: 3 }.to_string(),
+        "invalid separator at position 3"
+    );
+}
+
+/// Reads at most `max_read` bytes per call from `inner`, interrupting every other call.
+struct SlowReader<R> {
+    inner: R,
+    max_read: usize,
+    interrupt: bool,
+}
+
+impl<R: std::io::Read> std::io::Read for SlowReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(std::io::ErrorKind::Interrupted.into());
+        }
+        let len = buf.len().min(self.max_read);
+        self.inner.read(&mut buf[..len])
+    }
+}
+
+#[test]
+fn test_from_reader() {
+    let x = ubig!(_0x123456789abcdef0123456789abcdef0123456789abcdef).pow(7);
+    for radix in 2..=36 {
+        let s = x.in_radix(radix).to_string();
+        assert_eq!(
+            UBig::from_reader(s.as_bytes(), radix, false).unwrap(),
+            Ok(x.clone())
+        );
+        let slow = SlowReader {
+            inner: s.as_bytes(),
+            max_read: 7,
+            interrupt: false,
+        };
+        assert_eq!(
+            UBig::from_reader(slow, radix, false).unwrap(),
+            Ok(x.clone())
+        );
+    }
+    assert_eq!(
+        UBig::from_reader(&b"0"[..], 10, false).unwrap(),
+        Ok(ubig!(0))
+    );
+    assert_eq!(
+        UBig::from_reader(&b"000"[..], 16, false).unwrap(),
+        Ok(ubig!(0))
+    );
+    assert_eq!(
+        UBig::from_reader(&b" 12 34\r\n56\t"[..], 10, true).unwrap(),
+        Ok(ubig!(123456))
+    );
+
+    let invalid = |character, position| ParseError::InvalidDigit {
+        character,
+        position,
+    };
+    assert_eq!(
+        UBig::from_reader(&b"12 34"[..], 10, false).unwrap(),
+        Err(invalid(' ', 2))
+    );
+    assert_eq!(
+        UBig::from_reader(&b"12 3a"[..], 10, true).unwrap(),
+        Err(invalid('a', 4))
+    );
+    assert_eq!(
+        UBig::from_reader("12é".as_bytes(), 16, true).unwrap(),
+        Err(invalid('\u{fffd}', 2))
+    );
+    assert_eq!(
+        UBig::from_reader(&b"+12"[..], 10, false).unwrap(),
+        Err(invalid('+', 0))
+    );
+    assert_eq!(
+        UBig::from_reader(&b""[..], 10, false).unwrap(),
+        Err(ParseError::NoDigits)
+    );
+    assert_eq!(
+        UBig::from_reader(&b" \n "[..], 10, true).unwrap(),
+        Err(ParseError::NoDigits)
+    );
+}
+
+#[test]
+fn test_from_reader_file() {
+    use std::{fs, io::Write};
+
+    let x = ubig!(3).pow(UBig::MAX_BIT_LEN.min(400000) / 2);
+    let s = x.to_string();
+    let path = std::env::temp_dir().join(format!("ibig_from_reader_{}.txt", std::process::id()));
+    {
+        let mut file = fs::File::create(&path).unwrap();
+        for line in s.as_bytes().chunks(80) {
+            file.write_all(line).unwrap();
+            file.write_all(b"\n").unwrap();
+        }
+    }
+    let file = fs::File::open(&path).unwrap();
+    let res = UBig::from_reader(std::io::BufReader::new(file), 10, true).unwrap();
+    assert_eq!(res, s.parse());
+    assert_eq!(res, Ok(x.clone()));
+
+    // Short reads, so that the stream is refilled many times.
+    let file = fs::File::open(&path).unwrap();
+    let slow = SlowReader {
+        inner: file,
+        max_read: 100,
+        interrupt: false,
+    };
+    let res = UBig::from_reader(slow, 10, true).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(res, Ok(x));
+}
+
+#[test]
+fn test_from_reader_io_error() {
+    struct FailingReader;
+
+    impl std::io::Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::UnexpectedEof.into())
+        }
+    }
+
+    let err = UBig::from_reader(FailingReader, 10, false).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
+/// Decimal digits by repeated division by 10000.
+fn to_decimal_naive(x: &UBig) -> String {
+    let mut x = x.clone();
+    let mut groups = Vec::new();
+    while x != 0u8 {
+        groups.push(x.div_rem_word(10000));
+    }
+    let mut s = match groups.pop() {
+        None => return "0".to_string(),
+        Some(top) => top.to_string(),
+    };
+    for group in groups.iter().rev() {
+        s.push_str(&format!("{:04}", group));
+    }
+    s
+}
+
+#[test]
+fn test_format_large_decimal() {
+    // Up to 100000 digits, within the size limit for 16-bit words.
+    let digits = (UBig::MAX_BIT_LEN / 4).min(100000);
+    let x = ubig!(7).pow(digits * 1000 / 845) - ubig!(1);
+    let s = x.to_string();
+    assert!(s.len() >= digits - 10);
+    assert_eq!(s, to_decimal_naive(&x));
+}
+
+#[test]
+fn test_parse_large_matches_linear() {
+    // Lengths on both sides of the divide-and-conquer threshold, within the size limit for
+    // 16-bit words.
+    let max_digits = (UBig::MAX_BIT_LEN / 6).min(50000);
+    for &radix in [3, 10, 36].iter() {
+        let mut len = 1;
+        while len <= max_digits {
+            let s: String = (0..len)
+                .map(|i| std::char::from_digit((i * 7 + 3) as u32 % radix, radix).unwrap())
+                .collect();
+            // from_reader accumulates one digit at a time.
+            let linear = UBig::from_reader(s.as_bytes(), radix, false).unwrap();
+            assert_eq!(UBig::from_str_radix(&s, radix), linear);
+            len = len * 3 + 1;
+        }
+    }
+}
+
+#[test]
+fn test_digit_count() {
+    assert_eq!(ubig!(0).digit_count(10), 1);
+    assert_eq!(ubig!(0).digit_count(16), 1);
+    assert_eq!(ubig!(9).digit_count(10), 1);
+    assert_eq!(ubig!(10).digit_count(10), 2);
+    assert_eq!(ubig!(999).digit_count(10), 3);
+    assert_eq!(ubig!(1000).digit_count(10), 4);
+    assert_eq!(ubig!(255).digit_count(16), 2);
+    assert_eq!(ubig!(256).digit_count(16), 3);
+    assert_eq!(ubig!(7).digit_count(2), 3);
+    assert_eq!(ubig!(8).digit_count(2), 4);
+    assert_eq!(ubig!(35).digit_count(36), 1);
+    assert_eq!(ubig!(36).digit_count(36), 2);
+
+    let p = ubig!(10).pow(100);
+    assert_eq!((&p - ubig!(1)).digit_count(10), 100);
+    assert_eq!(p.digit_count(10), 101);
+    let mut p = ubig!(1);
+    for k in 1..=600 {
+        p *= ubig!(10);
+        assert_eq!((&p - ubig!(1)).digit_count(10), k);
+        assert_eq!(p.digit_count(10), k + 1);
+    }
+    let p = ubig!(1) << 200;
+    assert_eq!((&p - ubig!(1)).digit_count(16), 50);
+    assert_eq!(p.digit_count(16), 51);
+    assert_eq!(p.digit_count(32), 41);
+
+    for radix in 2..=36 {
+        for x in [ubig!(0), ubig!(1), ubig!(12345), ubig!(3).pow(150)].iter() {
+            assert_eq!(x.digit_count(radix), x.to_str_radix(radix).len());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_digit_count_invalid_radix() {
+    let _ = ubig!(10).digit_count(37);
+}
+
+#[test]
+fn test_debug_summary() {
+    let x = (ubig!(1) << 20000) - ubig!(1);
+    let words = (20000 + ibig::WORD_BITS - 1) / ibig::WORD_BITS;
+    let summary = format!(
+        "{} words, ~6021 decimal digits, 0x...ffffffff at bottom)",
+        words
+    );
+    assert_eq!(x.debug_summary(), format!("UBig({}", summary));
+    assert_eq!(format!("{:?}", x), format!("UBig({}", summary));
+    let x = IBig::from(x);
+    assert_eq!(format!("{:?}", x), format!("IBig({}", summary));
+    assert_eq!(format!("{:?}", -&x), format!("IBig(-{}", summary));
+    assert_eq!(x.debug_summary(), format!("IBig({}", summary));
+
+    assert_eq!(
+        ubig!(0x1234).debug_summary(),
+        "UBig(1 words, ~4 decimal digits, 0x...00001234 at bottom)"
+    );
+    assert_eq!(
+        ubig!(0).debug_summary(),
+        "UBig(0 words, ~1 decimal digits, 0x...00000000 at bottom)"
+    );
+    assert_eq!(
+        ibig!(-0x1234).debug_summary(),
+        "IBig(-1 words, ~4 decimal digits, 0x...00001234 at bottom)"
+    );
+
+    // Short enough to be printed in full.
+    let x = (ubig!(1) << 10000) - ubig!(1);
+    assert_eq!(format!("{:?}", x), x.to_string());
+    assert_eq!(format!("{:?}", -IBig::from(&x)), format!("-{}", x));
+}
+
+#[test]
+fn test_from_str_radix_trimmed() {
+    assert_eq!(UBig::from_str_radix_trimmed("123", 10), Ok(ubig!(123)));
+    assert_eq!(UBig::from_str_radix_trimmed(" +123 ", 10), Ok(ubig!(123)));
+    assert_eq!(
+        UBig::from_str_radix_trimmed("\t\t7ab\r\n", 32),
+        Ok(ubig!(7499))
+    );
+    assert_eq!(
+        UBig::from_str_radix_trimmed("  ffffffffffffffffffffffffffffffff\n", 16),
+        Ok(ubig!(_0xffffffffffffffffffffffffffffffff))
+    );
+    assert_eq!(
+        UBig::from_str_radix_trimmed(" 12 34 ", 10),
+        Err(ParseError::InvalidDigit {
+            character: ' ',
+            position: 3
+        })
+    );
+    assert_eq!(
+        UBig::from_str_radix_trimmed("\t+1\t2", 10),
+        Err(ParseError::InvalidDigit {
+            character: '\t',
+            position: 3
+        })
+    );
+    assert_eq!(
+        UBig::from_str_radix_trimmed("  -1", 10),
+        Err(ParseError::InvalidDigit {
+            character: '-',
+            position: 2
+        })
+    );
+    assert_eq!(
+        UBig::from_str_radix_trimmed(" \t ", 10),
+        Err(ParseError::NoDigits)
+    );
+    assert_eq!(
+        UBig::from_str_radix_trimmed(" + 1", 10),
+        Err(ParseError::InvalidDigit {
+            character: ' ',
+            position: 2
+        })
+    );
+    // Unicode whitespace is not trimmed.
+    assert!(UBig::from_str_radix_trimmed("\u{a0}1", 10).is_err());
+    // The strict version is unchanged.
+    assert!(UBig::from_str_radix(" 123", 10).is_err());
+
+    assert_eq!(IBig::from_str_radix_trimmed(" -123 ", 10), Ok(ibig!(-123)));
+    assert_eq!(IBig::from_str_radix_trimmed("\t+ff\n", 16), Ok(ibig!(0xff)));
+    assert_eq!(
+        IBig::from_str_radix_trimmed(" -1 0", 10),
+        Err(ParseError::InvalidDigit {
+            character: ' ',
+            position: 3
+        })
+    );
+    assert_eq!(
+        IBig::from_str_radix_trimmed("  -  ", 10),
+        Err(ParseError::NoDigits)
+    );
+    assert!(IBig::from_str_radix(" -1", 10).is_err());
 }
@@ -1,4 +1,9 @@
-use ibig::{ibig, ops::DivRem, ubig, UBig};
+use ibig::{
+    ibig,
+    modular::{BarrettCtx, MontgomeryCtx},
+    ops::DivRem,
+    ubig, IBig, UBig, Word,
+};
 use rand::{distributions::uniform::Uniform, prelude::*};
 
 #[test]
@@ -56,12 +61,12 @@ fn test_random_arithmetic() {
         };
         let num_cases = 10u32.pow(7 - log_num_bits);
         for _ in 0..num_cases {
-            let len_a = (&mut rng).gen_range(10..num_bits);
-            let len_b = (&mut rng).gen_range(10..num_bits);
-            let a = (&mut rng).gen_range(ubig!(100)..ubig!(1) << len_a);
-            let b = (&mut rng).gen_range(ubig!(100)..ubig!(1) << len_b);
-            let c = (&mut rng).sample(Uniform::new(ubig!(0), &a));
-            let radix = (&mut rng).gen_range(2..=36);
+            let len_a = rng.gen_range(10..num_bits);
+            let len_b = rng.gen_range(10..num_bits);
+            let a = rng.gen_range(ubig!(100)..ubig!(1) << len_a);
+            let b = rng.gen_range(ubig!(100)..ubig!(1) << len_b);
+            let c = rng.sample(Uniform::new(ubig!(0), &a));
+            let radix = rng.gen_range(2..=36);
 
             assert_eq!((&a + &b) % &p, ((&a % &p) + (&b % &p)) % &p);
             assert_eq!(&a + &b - &a, b);
@@ -76,3 +81,190 @@ fn test_random_arithmetic() {
         }
     }
 }
+
+#[test]
+fn test_random_below() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    for n in [
+        ubig!(1),
+        ubig!(2),
+        ubig!(7),
+        ubig!(1) << 64,
+        (ubig!(1) << 200) + ubig!(1),
+    ]
+    .iter()
+    {
+        for _ in 0..1000 {
+            assert!(UBig::random_below(n, &mut rng) < *n);
+        }
+    }
+    assert_eq!(UBig::random_below(&ubig!(1), &mut rng), ubig!(0));
+
+    let x = (0..1000)
+        .map(|_| UBig::random_below(&ubig!(5), &mut rng))
+        .max()
+        .unwrap();
+    assert_eq!(x, ubig!(4));
+}
+
+#[test]
+#[should_panic]
+fn test_random_below_zero() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let _ = UBig::random_below(&ubig!(0), &mut rng);
+}
+
+#[test]
+fn test_random_bits() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    assert_eq!(UBig::random_bits(0, &mut rng), ubig!(0));
+    for &bits in [1, 3, 63, 64, 65, 128, 200].iter() {
+        let xs: Vec<UBig> = (0..100)
+            .map(|_| UBig::random_bits(bits, &mut rng))
+            .collect();
+        assert!(xs.iter().all(|x| x.bit_len() <= bits));
+        // With 100 samples the top bit is set at least once with overwhelming probability.
+        assert!(xs.iter().any(|x| x.bit_len() == bits));
+    }
+}
+
+#[test]
+fn test_random_mod_inverse() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..100 {
+        let bits = rng.gen_range(2..1000);
+        let m = UBig::random_bits(bits, &mut rng) + ubig!(2);
+        let a = UBig::random_below(&(&m * ubig!(3)), &mut rng);
+        match a.mod_inverse(&m) {
+            Some(x) => {
+                assert!(x < m);
+                assert_eq!(&a * &x % &m, ubig!(1));
+            }
+            None => assert_ne!(a.gcd(&m), ubig!(1)),
+        }
+    }
+}
+
+#[test]
+fn test_random_gcd() {
+    let mut rng = StdRng::seed_from_u64(9);
+    for _ in 0..300 {
+        let common = UBig::random_bits(rng.gen_range(0..500), &mut rng) + ubig!(1);
+        let a = UBig::random_bits(rng.gen_range(0..2000), &mut rng) * &common;
+        let b = UBig::random_bits(rng.gen_range(0..2000), &mut rng) * &common;
+        if a == ubig!(0) && b == ubig!(0) {
+            continue;
+        }
+        let g = a.gcd(&b);
+        assert_eq!(g, a.extended_gcd(&b).0);
+        assert_eq!(&g % &common, ubig!(0));
+        assert_eq!(&a % &g, ubig!(0));
+        assert_eq!(&b % &g, ubig!(0));
+    }
+}
+
+#[test]
+fn test_random_word_ops() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..1000 {
+        let bits = rng.gen_range(0..300);
+        let a = UBig::random_bits(bits, &mut rng);
+        let w: Word = rng.gen();
+
+        let mut x = a.clone();
+        x.add_word(w);
+        assert_eq!(x, &a + UBig::from(w));
+
+        let mut x = a.clone();
+        x.mul_word(w);
+        assert_eq!(x, &a * UBig::from(w));
+
+        if w != 0 {
+            let mut x = a.clone();
+            let r = x.div_rem_word(w);
+            assert_eq!((x, UBig::from(r)), (&a).div_rem(UBig::from(w)));
+        }
+
+        if a >= w {
+            let mut x = a.clone();
+            x.sub_word(w);
+            assert_eq!(x, &a - UBig::from(w));
+        }
+    }
+}
+
+#[test]
+fn test_random_div_rem_sizes() {
+    // Sizes on both sides of the switch between simple and divide-and-conquer division.
+    let mut rng = StdRng::seed_from_u64(5);
+    let max_bits = (UBig::MAX_BIT_LEN / 2).min(200000);
+    for _ in 0..100 {
+        let a_bits = rng.gen_range(1..max_bits);
+        let b_bits = rng.gen_range(1..=a_bits);
+        let a = UBig::random_bits(a_bits, &mut rng);
+        let b = UBig::random_bits(b_bits, &mut rng) | ubig!(1);
+        let (q, r) = (&a).div_rem(&b);
+        assert!(r < b);
+        assert_eq!(&q * &b + &r, a);
+    }
+}
+
+#[test]
+fn test_random_mul_add() {
+    let mut rng = StdRng::seed_from_u64(6);
+    let max_bits = (UBig::MAX_BIT_LEN / 4).min(50000);
+    for _ in 0..100 {
+        let a = UBig::random_bits(rng.gen_range(1..max_bits), &mut rng);
+        let b = UBig::random_bits(rng.gen_range(1..max_bits), &mut rng);
+        let c = UBig::random_bits(rng.gen_range(1..2 * max_bits), &mut rng);
+        assert_eq!(a.mul_add(&b, &c), &a * &b + &c);
+
+        let a = if rng.gen() {
+            IBig::from(a)
+        } else {
+            -IBig::from(a)
+        };
+        let b = if rng.gen() {
+            IBig::from(b)
+        } else {
+            -IBig::from(b)
+        };
+        let c = if rng.gen() {
+            IBig::from(c)
+        } else {
+            -IBig::from(c)
+        };
+        assert_eq!(a.mul_add(&b, &c), &a * &b + &c);
+    }
+}
+
+#[test]
+fn test_random_montgomery_pow_mod() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let max_bits = (UBig::MAX_BIT_LEN / 8).min(3000);
+    for _ in 0..30 {
+        let modulus = UBig::random_bits(rng.gen_range(1..max_bits), &mut rng) | ubig!(1);
+        let ctx = MontgomeryCtx::new(&modulus).unwrap();
+        let base = UBig::random_bits(rng.gen_range(0..2 * max_bits), &mut rng);
+        // Small exponents use square-and-multiply, larger ones a sliding window.
+        let exp = UBig::random_bits(rng.gen_range(0..5000), &mut rng);
+        assert_eq!(ctx.pow_mod(&base, &exp), base.pow_mod(&exp, &modulus));
+    }
+}
+
+#[test]
+fn test_random_barrett_reduce() {
+    let mut rng = StdRng::seed_from_u64(8);
+    let max_bits = (UBig::MAX_BIT_LEN / 8).min(3000);
+    for _ in 0..100 {
+        let modulus = UBig::random_bits(rng.gen_range(1..max_bits), &mut rng) + ubig!(1);
+        let ctx = BarrettCtx::new(&modulus);
+        let square = &modulus * &modulus;
+        for _ in 0..10 {
+            let x = UBig::random_below(&square, &mut rng);
+            assert_eq!(ctx.reduce(&x), &x % &modulus);
+        }
+    }
+}
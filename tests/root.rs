@@ -0,0 +1,46 @@
+use ibig::{ibig, ubig, IBig, UBig};
+use std::convert::TryFrom;
+
+#[test]
+fn test_sqrt_ubig() {
+    for n in 0u64..1000 {
+        let s = UBig::from(n).sqrt();
+        let s = u64::try_from(s).unwrap();
+        assert!(s * s <= n && (s + 1) * (s + 1) > n);
+    }
+
+    let test_cases = [
+        ubig!(1),
+        ubig!(0xffffffff),
+        ubig!(0x100000000),
+        ubig!(0xffffffffffffffff),
+        ubig!(_0x123456789abcdef0123456789abcdef),
+        ubig!(3).pow(1001),
+        (ubig!(1) << 2000) - ubig!(1),
+    ];
+    for x in &test_cases {
+        let square = x * x;
+        assert_eq!(square.sqrt(), *x);
+        assert_eq!(square.sqrt_rem(), (x.clone(), ubig!(0)));
+        assert_eq!((&square - ubig!(1)).sqrt(), x - ubig!(1));
+        assert_eq!((&square + ubig!(1)).sqrt(), *x);
+        let below_next = &square + x + x;
+        assert_eq!(below_next.sqrt_rem(), (x.clone(), x + x));
+    }
+}
+
+#[test]
+fn test_sqrt_ibig() {
+    assert_eq!(ibig!(0).sqrt(), ibig!(0));
+    assert_eq!(ibig!(1).sqrt(), ibig!(1));
+    assert_eq!(ibig!(24).sqrt(), ibig!(4));
+    assert_eq!(ibig!(25).sqrt(), ibig!(5));
+    let x = IBig::from(ubig!(10).pow(100));
+    assert_eq!((&x * &x).sqrt(), x);
+}
+
+#[test]
+#[should_panic]
+fn test_sqrt_negative() {
+    let _ = ibig!(-4).sqrt();
+}
@@ -1,20 +1,26 @@
-use ibig::{ibig, ubig};
-use serde_test::{assert_de_tokens, assert_tokens, Token};
+use ibig::{ibig, ubig, IBig, UBig};
+use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
 
 #[test]
 fn test_ubig_serde() {
-    assert_tokens(&ubig!(0), &[Token::Seq { len: Some(0) }, Token::SeqEnd]);
-    assert_de_tokens(&ubig!(0), &[Token::Seq { len: None }, Token::SeqEnd]);
     assert_tokens(
-        &ubig!(17),
+        &ubig!(0).compact(),
+        &[Token::Seq { len: Some(0) }, Token::SeqEnd],
+    );
+    assert_de_tokens(
+        &ubig!(0).compact(),
+        &[Token::Seq { len: None }, Token::SeqEnd],
+    );
+    assert_tokens(
+        &ubig!(17).compact(),
         &[Token::Seq { len: Some(1) }, Token::U64(17), Token::SeqEnd],
     );
     assert_de_tokens(
-        &ubig!(17),
+        &ubig!(17).compact(),
         &[Token::Seq { len: None }, Token::U8(17), Token::SeqEnd],
     );
     assert_tokens(
-        &ubig!(0x123451234567890abcdef),
+        &ubig!(0x123451234567890abcdef).compact(),
         &[
             Token::Seq { len: Some(2) },
             Token::U64(0x1234567890abcdef),
@@ -23,7 +29,7 @@ fn test_ubig_serde() {
         ],
     );
     assert_de_tokens(
-        &ubig!(0x123451234567890abcdef),
+        &ubig!(0x123451234567890abcdef).compact(),
         &[
             Token::Seq { len: None },
             Token::U64(0x1234567890abcdef),
@@ -36,7 +42,7 @@ fn test_ubig_serde() {
 #[test]
 fn test_ibig_serde() {
     assert_tokens(
-        &ibig!(0),
+        &ibig!(0).compact(),
         &[
             Token::Tuple { len: 2 },
             Token::UnitVariant {
@@ -49,7 +55,7 @@ fn test_ibig_serde() {
         ],
     );
     assert_de_tokens(
-        &ibig!(0),
+        &ibig!(0).compact(),
         &[
             Token::Seq { len: None },
             Token::UnitVariant {
@@ -62,7 +68,7 @@ fn test_ibig_serde() {
         ],
     );
     assert_tokens(
-        &ibig!(17),
+        &ibig!(17).compact(),
         &[
             Token::Tuple { len: 2 },
             Token::UnitVariant {
@@ -76,7 +82,48 @@ fn test_ibig_serde() {
         ],
     );
     assert_tokens(
-        &ibig!(-17),
+        &ibig!(-17).compact(),
+        &[
+            Token::Tuple { len: 2 },
+            Token::UnitVariant {
+                name: "Sign",
+                variant: "Negative",
+            },
+            Token::Seq { len: Some(1) },
+            Token::U64(17),
+            Token::SeqEnd,
+            Token::TupleEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_ubig_serde_readable() {
+    assert_tokens(&ubig!(0).readable(), &[Token::Str("0")]);
+    assert_tokens(
+        &ubig!(_1234567890123456789012345678901234567890).readable(),
+        &[Token::Str("1234567890123456789012345678901234567890")],
+    );
+    assert_de_tokens(&ubig!(17).readable(), &[Token::U64(17)]);
+    // The compact format is also accepted.
+    assert_de_tokens(
+        &ubig!(0x123451234567890abcdef).readable(),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::U64(0x1234567890abcdef),
+            Token::U64(0x12345),
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_ibig_serde_readable() {
+    assert_tokens(&ibig!(0).readable(), &[Token::Str("0")]);
+    assert_tokens(&ibig!(-17).readable(), &[Token::Str("-17")]);
+    assert_de_tokens(&ibig!(-17).readable(), &[Token::I64(-17)]);
+    assert_de_tokens(
+        &ibig!(-17).readable(),
         &[
             Token::Tuple { len: 2 },
             Token::UnitVariant {
@@ -90,3 +137,42 @@ fn test_ibig_serde() {
         ],
     );
 }
+
+#[test]
+fn test_serde_json() {
+    let x = ubig!(_1234567890123456789012345678901234567890);
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, "\"1234567890123456789012345678901234567890\"");
+    assert_eq!(serde_json::from_str::<UBig>(&json).unwrap(), x);
+
+    let y = -IBig::from(x);
+    let json = serde_json::to_string(&y).unwrap();
+    assert_eq!(json, "\"-1234567890123456789012345678901234567890\"");
+    assert_eq!(serde_json::from_str::<IBig>(&json).unwrap(), y);
+
+    assert_eq!(serde_json::from_str::<UBig>("123").unwrap(), ubig!(123));
+    assert_eq!(serde_json::from_str::<IBig>("-123").unwrap(), ibig!(-123));
+    assert!(serde_json::from_str::<UBig>("\"12a\"").is_err());
+    assert!(serde_json::from_str::<UBig>("\"-1\"").is_err());
+    assert!(serde_json::from_str::<IBig>("\"\"").is_err());
+}
+
+#[test]
+fn test_serde_cbor() {
+    // CBOR is not human-readable, so it gets the compact format.
+    for x in &[
+        ubig!(0),
+        ubig!(17),
+        ubig!(_1234567890123456789012345678901234567890),
+    ] {
+        let bytes = serde_cbor::to_vec(x).unwrap();
+        let value: serde_cbor::Value = serde_cbor::from_slice(&bytes).unwrap();
+        assert!(matches!(value, serde_cbor::Value::Array(_)));
+        assert_eq!(serde_cbor::from_slice::<UBig>(&bytes).unwrap(), *x);
+
+        for y in &[IBig::from(x), -IBig::from(x)] {
+            let bytes = serde_cbor::to_vec(y).unwrap();
+            assert_eq!(serde_cbor::from_slice::<IBig>(&bytes).unwrap(), *y);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use ibig::{ibig, ubig};
+use ibig::{ibig, ubig, IBig};
 
 #[test]
 #[allow(clippy::identity_op, clippy::op_ref)]
@@ -217,3 +217,68 @@ fn test_ibig_shr() {
         assert_eq!(x, *c);
     }
 }
+
+#[test]
+fn test_ubig_shift_word_multiples() {
+    let x = ubig!(_0xfedcba9876543210fedcba9876543210fedcba9876543210);
+    for words in 0..5 {
+        let bits = 64 * words;
+        let shifted = &x << bits;
+        assert_eq!(&shifted >> bits, x);
+        assert_eq!(shifted.trailing_zeros(), Some(bits + 4));
+        assert_eq!(shifted.bit_len(), x.bit_len() + bits);
+
+        let mut y = x.clone();
+        y <<= bits;
+        assert_eq!(y, shifted);
+        y >>= bits;
+        assert_eq!(y, x);
+    }
+    assert_eq!(&x >> 64, ubig!(_0xfedcba9876543210fedcba9876543210));
+    assert_eq!(&x >> 128, ubig!(0xfedcba9876543210));
+    assert_eq!(&x >> 191, ubig!(1));
+}
+
+#[test]
+fn test_ubig_shr_past_end() {
+    let x = ubig!(_0xfedcba9876543210fedcba9876543210fedcba9876543210);
+    assert_eq!(&x >> 192, ubig!(0));
+    assert_eq!(&x >> 193, ubig!(0));
+    assert_eq!(&x >> 1000, ubig!(0));
+    assert_eq!(&x >> usize::MAX, ubig!(0));
+    assert_eq!(ubig!(5) >> 3, ubig!(0));
+    assert_eq!(ubig!(5) >> 64, ubig!(0));
+
+    let mut y = x;
+    y >>= 192;
+    assert_eq!(y, ubig!(0));
+}
+
+#[test]
+fn test_ibig_shift_i64() {
+    let values = [
+        0i64,
+        1,
+        -1,
+        -2,
+        -3,
+        7,
+        -7,
+        -8,
+        -9,
+        0x1234_5678,
+        -0x1234_5678,
+        i64::MAX,
+        i64::MIN,
+        i64::MIN + 1,
+    ];
+    for &x in &values {
+        for shift in 0..64 {
+            assert_eq!(IBig::from(x) >> shift, IBig::from(x >> shift));
+            assert_eq!(IBig::from(x) << shift, IBig::from(i128::from(x) << shift));
+        }
+        assert_eq!(IBig::from(x) >> 64, IBig::from(x >> 63));
+    }
+    assert_eq!(ibig!(-1) >> 1, ibig!(-1));
+    assert_eq!(ibig!(-3) >> 1, ibig!(-2));
+}
@@ -1,11 +1,10 @@
 use ibig::{
     ibig,
     ops::{Abs, UnsignedAbs},
-    ubig,
+    ubig, IBig, Sign,
 };
 
 #[test]
-#[allow(clippy::double_neg)]
 fn test_neg() {
     assert_eq!(-ibig!(123), ibig!(-123));
     assert_eq!(-ibig!(-123), ibig!(123));
@@ -36,3 +35,52 @@ fn test_signum() {
     assert_eq!(ibig!(0).signum(), ibig!(0));
     assert_eq!(ibig!(500).signum(), ibig!(1));
 }
+
+#[test]
+fn test_is_positive_negative() {
+    assert!(ibig!(5).is_positive());
+    assert!(!ibig!(5).is_negative());
+    assert!(!ibig!(-5).is_positive());
+    assert!(ibig!(-5).is_negative());
+    assert!(!ibig!(0).is_positive());
+    assert!(!ibig!(0).is_negative());
+
+    // Negating zero keeps it canonical.
+    let zero = -ibig!(0);
+    assert_eq!(zero, ibig!(0));
+    assert!(!zero.is_negative());
+    assert_eq!(zero.signum(), ibig!(0));
+    assert!(!(-&ibig!(0)).is_negative());
+    assert!(!(ibig!(-5) + ibig!(5)).is_negative());
+    assert!(!(ibig!(-5) * ibig!(0)).is_negative());
+}
+
+#[test]
+fn test_sign_parts() {
+    assert_eq!(ibig!(5).sign(), Sign::Positive);
+    assert_eq!(ibig!(-5).sign(), Sign::Negative);
+    assert_eq!(ibig!(0).sign(), Sign::Positive);
+    assert_eq!((-ibig!(0)).sign(), Sign::Positive);
+    assert_eq!(-Sign::Positive, Sign::Negative);
+
+    assert_eq!(ibig!(-5).into_parts(), (Sign::Negative, ubig!(5)));
+    assert_eq!(ibig!(0).into_parts(), (Sign::Positive, ubig!(0)));
+    assert_eq!(IBig::from_parts(Sign::Negative, ubig!(0)), ibig!(0));
+    assert_eq!(
+        IBig::from_parts(Sign::Negative, ubig!(0)).into_parts(),
+        (Sign::Positive, ubig!(0))
+    );
+
+    for x in [
+        ibig!(0),
+        ibig!(1),
+        ibig!(-1),
+        ibig!(_0x123456789abcdef0123456789),
+        ibig!(-_0x123456789abcdef0123456789),
+    ]
+    .iter()
+    {
+        let (sign, magnitude) = x.clone().into_parts();
+        assert_eq!(IBig::from_parts(sign, magnitude), *x);
+    }
+}